@@ -0,0 +1,92 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+pub type Result<T> = std::result::Result<T, PuzzleError>;
+
+#[derive(Debug)]
+pub enum PuzzleError {
+    /// The input could not be parsed, `line` and `column` are counted from 1.
+    ParserError {
+        line: usize,
+        column: usize,
+        text: String,
+        msg: &'static str,
+    },
+    LogicError {
+        msg: &'static str,
+    },
+    IoError {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl PuzzleError {
+    /// Creates a parser error pointing at `text`, which has to be a slice of `line`.
+    pub fn parser(line_index: usize, line: &str, text: &str, msg: &'static str) -> Self {
+        let offset = (text.as_ptr() as usize)
+            .checked_sub(line.as_ptr() as usize)
+            .filter(|offset| offset + text.len() <= line.len() && line.is_char_boundary(*offset))
+            .unwrap_or(0);
+        PuzzleError::ParserError {
+            line: line_index + 1,
+            column: line[..offset].chars().count() + 1,
+            text: text.to_string(),
+            msg,
+        }
+    }
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::ParserError { line, column, text, msg } => {
+                write!(f, "Failed to parse input at line {}, column {}: {} (found `{}`)", line, column, msg, text)
+            }
+            PuzzleError::LogicError { msg } => {
+                write!(f, "Failed to solve puzzle: {}", msg)
+            }
+            PuzzleError::IoError { path, source } => {
+                write!(f, "Could not read input {}: {}", path.display(), source)
+            }
+        }
+    }
+}
+
+impl Error for PuzzleError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PuzzleError::IoError { source, .. } => Some(source),
+            PuzzleError::ParserError { .. } | PuzzleError::LogicError { .. } => None,
+        }
+    }
+}
+
+/// Parses `text`, a slice of `line`, into a number.
+pub fn parse_number<T: FromStr>(line_index: usize, line: &str, text: &str) -> Result<T> {
+    T::from_str(text).map_err(|_| PuzzleError::parser(line_index, line, text, "Invalid number!"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parser_error_position() {
+        let line = "Card 1: 41 4x 83";
+        let error = parse_number::<u64>(2, line, &line[11..13]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Failed to parse input at line 3, column 12: Invalid number! (found `4x`)"
+        );
+    }
+
+    #[test]
+    fn test_parser_error_foreign_text() {
+        let error = PuzzleError::parser(0, "abc", "xyz", "Unexpected text!");
+        assert!(matches!(error, PuzzleError::ParserError { line: 1, column: 1, .. }));
+    }
+}
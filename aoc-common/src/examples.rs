@@ -0,0 +1,127 @@
+use std::fs::{self, File};
+use std::io::{self, BufReader, ErrorKind};
+use std::path::{Path, PathBuf};
+
+use crate::input::read_file;
+use crate::{solve_part1, solve_part2, solve_stream_part1, solve_stream_part2, PuzzleError, Result, Solution, StreamingSolution};
+
+/// Extension of the files holding the expected answer of an example, named `<input>.part<N>.answer`.
+pub const ANSWER_EXTENSION: &str = "answer";
+
+#[derive(Debug)]
+pub struct Example {
+    pub name: String,
+    pub part: u8,
+    pub input_path: PathBuf,
+    pub expected: String,
+}
+
+fn invalid_answer_file(path: &Path) -> PuzzleError {
+    PuzzleError::IoError {
+        path: path.to_path_buf(),
+        source: io::Error::new(
+            ErrorKind::InvalidData,
+            format!("answer files have to be named `<input>.part<1|2>.{}`", ANSWER_EXTENSION),
+        ),
+    }
+}
+
+/// Collects the examples of `resources_dir`, every answer file is paired with the `.txt` input of the same name.
+pub fn find_examples(resources_dir: &Path) -> Result<Vec<Example>> {
+    let io_error = |source| PuzzleError::IoError { path: resources_dir.to_path_buf(), source };
+    let mut examples = Vec::new();
+    for entry in fs::read_dir(resources_dir).map_err(io_error)? {
+        let path = entry.map_err(io_error)?.path();
+        if path.extension().is_none_or(|extension| extension != ANSWER_EXTENSION) {
+            continue;
+        }
+
+        let (name, part) = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.rsplit_once(".part"))
+            .and_then(|(name, part)| Some((name, part.parse::<u8>().ok()?)))
+            .filter(|(_, part)| matches!(part, 1 | 2))
+            .ok_or_else(|| invalid_answer_file(&path))?;
+
+        examples.push(Example {
+            name: name.to_string(),
+            part,
+            input_path: resources_dir.join(format!("{}.txt", name)),
+            expected: read_file(&path)?.trim().to_string(),
+        });
+    }
+    examples.sort_by(|x, y| (&x.name, x.part).cmp(&(&y.name, y.part)));
+    Ok(examples)
+}
+
+/// Solves the example and returns the answer of the solution.
+pub fn solve_example<S: Solution>(example: &Example) -> Result<String> {
+    let input = read_file(&example.input_path)?;
+    let solved = match example.part {
+        1 => solve_part1::<S>(&input)?,
+        _ => solve_part2::<S>(&input)?,
+    };
+    Ok(solved.answer)
+}
+
+/// Solves the example reading its input as a stream and returns the answer of the solution.
+pub fn solve_stream_example<S: StreamingSolution>(example: &Example) -> Result<String> {
+    let file = File::open(&example.input_path).map_err(|source| PuzzleError::IoError { path: example.input_path.clone(), source })?;
+    let mut reader = BufReader::new(file);
+    let solved = match example.part {
+        1 => solve_stream_part1::<S>(&mut reader)?,
+        _ => solve_stream_part2::<S>(&mut reader)?,
+    };
+    Ok(solved.answer)
+}
+
+fn check_examples(resources_dir: &Path, day: u8, solve: impl Fn(&Example) -> Result<String>) {
+    let examples = find_examples(resources_dir).unwrap_or_else(|error| panic!("{}", error));
+    assert!(!examples.is_empty(), "No examples found in {}", resources_dir.display());
+
+    let failures: Vec<String> = examples
+        .iter()
+        .filter_map(|example| match solve(example) {
+            Ok(actual) if actual == example.expected => None,
+            Ok(actual) => Some(format!(
+                "{} part {}: expected {}, got {}",
+                example.name, example.part, example.expected, actual
+            )),
+            Err(error) => Some(format!("{} part {}: {}", example.name, example.part, error)),
+        })
+        .collect();
+    assert!(
+        failures.is_empty(),
+        "{} of {} examples failed for day {}:\n{}",
+        failures.len(),
+        examples.len(),
+        day,
+        failures.join("\n")
+    );
+}
+
+/// Checks every example of `resources_dir` and panics with a report of all examples that did not match.
+pub fn assert_examples<S: Solution>(resources_dir: impl AsRef<Path>) {
+    check_examples(resources_dir.as_ref(), S::DAY, solve_example::<S>);
+}
+
+/// Same as [`assert_examples`], but with the inputs streamed into the streaming parts of the solution.
+pub fn assert_stream_examples<S: StreamingSolution>(resources_dir: impl AsRef<Path>) {
+    check_examples(resources_dir.as_ref(), S::DAY, solve_stream_example::<S>);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_examples() -> Result<()> {
+        let examples = find_examples(Path::new("../day05/resources"))?;
+        let names: Vec<(&str, u8)> = examples.iter().map(|example| (example.name.as_str(), example.part)).collect();
+        assert_eq!(names, vec![("test_input01", 1), ("test_input01", 2)]);
+        assert!(examples[0].input_path.ends_with("test_input01.txt"));
+        assert_eq!(examples[1].expected, "46");
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+use std::env;
+use std::fs;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, ErrorKind, Read};
+use std::path::{Path, PathBuf};
+
+use crate::{PuzzleError, Result};
+
+/// Directory holding personal puzzle inputs named `dayNN.txt`, overrides the bundled inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Name of the input every day's solution is run against by default.
+pub const DEFAULT_INPUT_NAME: &str = "input";
+
+pub fn default_input_path(day: u8) -> Result<PathBuf> {
+    input_path_in(env::var_os(INPUT_DIR_VAR).as_deref().map(Path::new), day)
+}
+
+/// Input of `day` in `input_dir`, or the bundled input without one.
+fn input_path_in(input_dir: Option<&Path>, day: u8) -> Result<PathBuf> {
+    if let Some(input_dir) = input_dir {
+        return Ok(input_dir.join(format!("day{:02}.txt", day)));
+    }
+
+    resource_path(day, DEFAULT_INPUT_NAME).map_err(|error| match error {
+        PuzzleError::IoError { path, source } => PuzzleError::IoError {
+            source: io::Error::new(
+                source.kind(),
+                format!("{}, pass the input path, `-` to read from stdin or set {}", source, INPUT_DIR_VAR),
+            ),
+            path,
+        },
+        error => error,
+    })
+}
+
+/// Path of the input `name` bundled in the resources of the day's crate.
+pub fn resource_path(day: u8, name: &str) -> Result<PathBuf> {
+    find_in_ancestors(&Path::new(&format!("day{:02}", day)).join("resources").join(format!("{}.txt", name)))
+}
+
+/// Searches `relative_path` in the current directory and all of its parents.
+pub fn find_in_ancestors(relative_path: &Path) -> Result<PathBuf> {
+    let current_dir = env::current_dir().map_err(|source| PuzzleError::IoError { path: PathBuf::from("."), source })?;
+    current_dir
+        .ancestors()
+        .map(|dir| dir.join(relative_path))
+        .find(|path| path.is_file())
+        .ok_or_else(|| PuzzleError::IoError {
+            source: io::Error::new(
+                ErrorKind::NotFound,
+                format!("not found in {} or one of its parents", current_dir.display()),
+            ),
+            path: relative_path.to_path_buf(),
+        })
+}
+
+pub fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|source| PuzzleError::IoError { path: path.to_path_buf(), source })
+}
+
+fn read_stdin() -> Result<String> {
+    let mut input = String::new();
+    io::stdin()
+        .read_to_string(&mut input)
+        .map_err(|source| PuzzleError::IoError { path: PathBuf::from("-"), source })?;
+    Ok(input)
+}
+
+/// Loads the input from `source`, which is either a file path or `-` for stdin.
+/// Without a source the conventional location of the day's input is used.
+pub fn load_input(day: u8, source: Option<&str>) -> Result<String> {
+    match source {
+        Some("-") => read_stdin(),
+        Some(path) => read_file(Path::new(path)),
+        None => read_file(&default_input_path(day)?),
+    }
+}
+
+/// Opens the input from `source` like [`load_input`], but for reading it piece by piece instead of all at once.
+pub fn open_input(day: u8, source: Option<&str>) -> Result<Box<dyn BufRead>> {
+    let path = match source {
+        Some("-") => return Ok(Box::new(io::stdin().lock())),
+        Some(path) => PathBuf::from(path),
+        None => default_input_path(day)?,
+    };
+    let file = File::open(&path).map_err(|source| PuzzleError::IoError { path, source })?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+/// Loads the input from the first command line argument of the running binary.
+pub fn read_input(day: u8) -> Result<String> {
+    let source = env::args().nth(1);
+    load_input(day, source.as_deref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_input_path() -> Result<()> {
+        let path = input_path_in(None, 1)?;
+        assert!(path.ends_with("day01/resources/input.txt"));
+        assert_eq!(input_path_in(Some(Path::new("inputs")), 7)?, Path::new("inputs/day07.txt"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_resource_path() -> Result<()> {
+        let path = resource_path(5, "test_input01")?;
+        assert!(path.ends_with("day05/resources/test_input01.txt"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_input() -> Result<()> {
+        let path = resource_path(5, DEFAULT_INPUT_NAME)?;
+        let mut lines = open_input(5, path.to_str())?.lines();
+        assert!(lines.next().is_some());
+        assert!(matches!(open_input(1, Some("does/not/exist.txt")), Err(PuzzleError::IoError { .. })));
+        Ok(())
+    }
+
+    #[test]
+    fn test_missing_input_file() {
+        let error = load_input(1, Some("does/not/exist.txt")).unwrap_err();
+        assert!(matches!(error, PuzzleError::IoError { .. }));
+        assert!(error.to_string().contains("does/not/exist.txt"));
+    }
+}
@@ -0,0 +1,125 @@
+use std::fmt::Display;
+use std::io::BufRead;
+use std::time::{Duration, Instant};
+
+pub mod error;
+pub mod examples;
+pub mod input;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod stream;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use error::{PuzzleError, Result};
+
+/// Interface implemented by the solution of every day.
+///
+/// The puzzle input is parsed once into [`Solution::Input`] and then shared by both parts.
+pub trait Solution {
+    /// Day of the advent calendar this solution belongs to.
+    const DAY: u8;
+
+    /// Parsed representation of the puzzle input, may borrow from the input text.
+    type Input<'a>;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1>;
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2>;
+}
+
+/// Solution that can also consume its input line by line, without holding all of it in memory.
+///
+/// The streaming parts have to return the same answers as [`Solution::part1`] and [`Solution::part2`].
+pub trait StreamingSolution: Solution {
+    fn part1_stream(reader: &mut dyn BufRead) -> Result<Self::Answer1>;
+    fn part2_stream(reader: &mut dyn BufRead) -> Result<Self::Answer2>;
+}
+
+/// Answer of a single part together with the time spent parsing the input and solving the part.
+#[derive(Debug, Clone)]
+pub struct Solved {
+    pub answer: String,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+/// Phases of solving a part, see [`PhaseObserver`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    Parse,
+    Solve,
+}
+
+/// Notified around every phase of solving a part, e.g. to take measurements besides the wall time.
+pub trait PhaseObserver {
+    fn enter(&mut self, phase: Phase);
+    fn exit(&mut self, phase: Phase);
+}
+
+impl PhaseObserver for () {
+    fn enter(&mut self, _phase: Phase) {}
+    fn exit(&mut self, _phase: Phase) {}
+}
+
+fn solve_timed<S: Solution, A: Display>(
+    input: &str,
+    part: fn(&S::Input<'_>) -> Result<A>,
+    observer: &mut dyn PhaseObserver,
+) -> Result<Solved> {
+    observer.enter(Phase::Parse);
+    let now = Instant::now();
+    let parsed = S::parse(input);
+    let parse_time = now.elapsed();
+    observer.exit(Phase::Parse);
+    let parsed = parsed?;
+
+    observer.enter(Phase::Solve);
+    let now = Instant::now();
+    let answer = part(&parsed).map(|answer| answer.to_string());
+    let solve_time = now.elapsed();
+    observer.exit(Phase::Solve);
+
+    Ok(Solved {
+        answer: answer?,
+        parse_time,
+        solve_time,
+    })
+}
+
+pub fn solve_part1<S: Solution>(input: &str) -> Result<Solved> {
+    solve_timed::<S, S::Answer1>(input, S::part1, &mut ())
+}
+
+pub fn solve_part2<S: Solution>(input: &str) -> Result<Solved> {
+    solve_timed::<S, S::Answer2>(input, S::part2, &mut ())
+}
+
+/// Solves a part from a stream, parsing and solving are interleaved so all the time is counted as solving.
+fn solve_stream_timed<A: Display>(reader: &mut dyn BufRead, part: fn(&mut dyn BufRead) -> Result<A>) -> Result<Solved> {
+    let now = Instant::now();
+    let answer = part(reader)?.to_string();
+    Ok(Solved {
+        answer,
+        parse_time: Duration::ZERO,
+        solve_time: now.elapsed(),
+    })
+}
+
+pub fn solve_stream_part1<S: StreamingSolution>(reader: &mut dyn BufRead) -> Result<Solved> {
+    solve_stream_timed(reader, S::part1_stream)
+}
+
+pub fn solve_stream_part2<S: StreamingSolution>(reader: &mut dyn BufRead) -> Result<Solved> {
+    solve_stream_timed(reader, S::part2_stream)
+}
+
+pub fn solve_part1_observed<S: Solution>(input: &str, observer: &mut dyn PhaseObserver) -> Result<Solved> {
+    solve_timed::<S, S::Answer1>(input, S::part1, observer)
+}
+
+pub fn solve_part2_observed<S: Solution>(input: &str, observer: &mut dyn PhaseObserver) -> Result<Solved> {
+    solve_timed::<S, S::Answer2>(input, S::part2, observer)
+}
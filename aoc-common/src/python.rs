@@ -0,0 +1,118 @@
+use std::ffi::CStr;
+
+use pyo3::exceptions::{PyOSError, PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyModule};
+
+use crate::{PuzzleError, Solution};
+
+/// Invalid inputs raise a `ValueError`, failures to solve a `RuntimeError` and unreadable files an `OSError`.
+impl From<PuzzleError> for PyErr {
+    fn from(error: PuzzleError) -> Self {
+        let message = error.to_string();
+        match error {
+            PuzzleError::ParserError { .. } => PyValueError::new_err(message),
+            PuzzleError::LogicError { .. } => PyRuntimeError::new_err(message),
+            PuzzleError::IoError { .. } => PyOSError::new_err(message),
+        }
+    }
+}
+
+/// Answer of `part` for the `part1` and `part2` functions of every module.
+pub fn answer<S: Solution>(input: &str, part: u8) -> PyResult<String> {
+    let parsed = S::parse(input)?;
+    match part {
+        1 => Ok(S::part1(&parsed)?.to_string()),
+        2 => Ok(S::part2(&parsed)?.to_string()),
+        _ => Err(PyValueError::new_err("The part has to be 1 or 2")),
+    }
+}
+
+/// Defines the `part1` and `part2` functions of a day's module, which take the input text and return the answer.
+///
+/// The calling crate has to depend on `pyo3` and add both functions to its module with `wrap_pyfunction!`.
+#[macro_export]
+macro_rules! python_parts {
+    ($solution:ident) => {
+        #[pyo3::pyfunction]
+        fn part1(input: &str) -> pyo3::PyResult<String> {
+            $crate::python::answer::<$solution>(input, 1)
+        }
+
+        #[pyo3::pyfunction]
+        fn part2(input: &str) -> pyo3::PyResult<String> {
+            $crate::python::answer::<$solution>(input, 2)
+        }
+    };
+}
+
+/// Runs the Python `code` with the module created by `init` bound to `name`, to test the bindings from Python.
+pub fn run_with_module(name: &str, init: fn(&Bound<'_, PyModule>) -> PyResult<()>, code: &CStr) -> PyResult<()> {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let module = PyModule::new(py, name)?;
+        init(&module)?;
+        let globals = PyDict::new(py);
+        globals.set_item(name, module)?;
+        py.run(code, Some(&globals), None)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use pyo3::wrap_pyfunction;
+
+    use super::*;
+    use crate::error::parse_number;
+
+    /// Sums and multiplies one number per line.
+    struct Numbers;
+
+    impl Solution for Numbers {
+        const DAY: u8 = 1;
+        type Input<'a> = Vec<u64>;
+        type Answer1 = u64;
+        type Answer2 = u64;
+
+        fn parse(input: &str) -> crate::Result<Self::Input<'_>> {
+            input.lines().enumerate().map(|(line_index, line)| parse_number(line_index, line, line)).collect()
+        }
+
+        fn part1(input: &Self::Input<'_>) -> crate::Result<Self::Answer1> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(input: &Self::Input<'_>) -> crate::Result<Self::Answer2> {
+            Ok(input.iter().product())
+        }
+    }
+
+    crate::python_parts!(Numbers);
+
+    fn numbers(m: &Bound<'_, PyModule>) -> PyResult<()> {
+        m.add_function(wrap_pyfunction!(part1, m)?)?;
+        m.add_function(wrap_pyfunction!(part2, m)?)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_parts_from_python() -> PyResult<()> {
+        let code = c"
+assert numbers.part1('2\\n3\\n4') == '9'
+assert numbers.part2('2\\n3\\n4') == '24'
+try:
+    numbers.part1('2\\nthree')
+    raise AssertionError('invalid input was accepted')
+except ValueError as error:
+    assert 'line 2' in str(error)
+";
+        run_with_module("numbers", numbers, code)
+    }
+
+    #[test]
+    fn test_unknown_part() {
+        pyo3::prepare_freethreaded_python();
+        let error = answer::<Numbers>("2", 7).unwrap_err();
+        Python::with_gil(|py| assert!(error.is_instance_of::<PyValueError>(py)));
+    }
+}
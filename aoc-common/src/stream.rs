@@ -0,0 +1,59 @@
+use std::io::BufRead;
+use std::path::PathBuf;
+
+use crate::{PuzzleError, Result};
+
+/// Path reported by read errors of streamed inputs, which do not know where their reader gets the data from.
+pub const STREAM_PATH: &str = "<stream>";
+
+/// Calls `f` with the index and the text of every line of `reader`, reading one line at a time into a reused buffer.
+///
+/// Lines are split like [`str::lines`] does, so streaming solutions see the same lines as the ones reading the whole input.
+pub fn for_each_line<R: BufRead>(mut reader: R, mut f: impl FnMut(usize, &str) -> Result<()>) -> Result<()> {
+    let mut buffer = String::new();
+    let mut line_index = 0;
+    loop {
+        buffer.clear();
+        let read = reader
+            .read_line(&mut buffer)
+            .map_err(|source| PuzzleError::IoError { path: PathBuf::from(STREAM_PATH), source })?;
+        if read == 0 {
+            return Ok(());
+        }
+        let line = match buffer.strip_suffix('\n') {
+            Some(line) => line.strip_suffix('\r').unwrap_or(line),
+            None => &buffer,
+        };
+        f(line_index, line)?;
+        line_index += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect_lines(input: &str) -> Result<Vec<(usize, String)>> {
+        let mut lines = Vec::new();
+        for_each_line(input.as_bytes(), |line_index, line| {
+            lines.push((line_index, line.to_string()));
+            Ok(())
+        })?;
+        Ok(lines)
+    }
+
+    #[test]
+    fn test_lines_match_str_lines() -> Result<()> {
+        for input in ["", "a", "a\n", "a\nb", "a\r\nb\r\n", "a\n\nb\n", "a\rb\r", "\n"] {
+            let expected: Vec<(usize, String)> = input.lines().map(str::to_string).enumerate().collect();
+            assert_eq!(collect_lines(input)?, expected, "{:?}", input);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        let error = for_each_line(&b"a\n\xff\n"[..], |_, _| Ok(())).unwrap_err();
+        assert!(matches!(error, PuzzleError::IoError { .. }));
+    }
+}
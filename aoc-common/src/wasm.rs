@@ -0,0 +1,41 @@
+pub use wasm_bindgen;
+use wasm_bindgen::JsError;
+
+use crate::Solution;
+
+/// Answer of `part` for the exports to JavaScript, errors become JavaScript `Error`s.
+///
+/// Unlike [`crate::solve_part1`] nothing is timed, `std::time::Instant` is not available in the browser.
+pub fn answer<S: Solution>(input: &str, part: u8) -> Result<String, JsError> {
+    let to_js_error = |error: crate::PuzzleError| JsError::new(&error.to_string());
+    let parsed = S::parse(input).map_err(to_js_error)?;
+    match part {
+        1 => S::part1(&parsed).map(|answer| answer.to_string()).map_err(to_js_error),
+        2 => S::part2(&parsed).map(|answer| answer.to_string()).map_err(to_js_error),
+        _ => Err(JsError::new("The part has to be 1 or 2")),
+    }
+}
+
+/// Exports `part1` and `part2` of a [`Solution`] to JavaScript from a `wasm` module of the calling crate.
+///
+/// Every day is built into a module of its own, so the names only have to be unique within a day.
+#[macro_export]
+macro_rules! wasm_exports {
+    ($solution:ident) => {
+        pub mod wasm {
+            use $crate::wasm::wasm_bindgen::prelude::{wasm_bindgen, JsError};
+
+            use super::$solution;
+
+            #[wasm_bindgen(wasm_bindgen = $crate::wasm::wasm_bindgen)]
+            pub fn part1(input: &str) -> ::std::result::Result<String, JsError> {
+                $crate::wasm::answer::<$solution>(input, 1)
+            }
+
+            #[wasm_bindgen(wasm_bindgen = $crate::wasm::wasm_bindgen)]
+            pub fn part2(input: &str) -> ::std::result::Result<String, JsError> {
+                $crate::wasm::answer::<$solution>(input, 2)
+            }
+        }
+    };
+}
@@ -0,0 +1,46 @@
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+use aoc_common::error::parse_number;
+use aoc_common::{Result, Solution};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+/// Sums and multiplies one number per line.
+struct Numbers;
+
+impl Solution for Numbers {
+    const DAY: u8 = 1;
+    type Input<'a> = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input.lines().enumerate().map(|(line_index, line)| parse_number(line_index, line, line)).collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1> {
+        Ok(input.iter().sum())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2> {
+        Ok(input.iter().product())
+    }
+}
+
+aoc_common::wasm_exports!(Numbers);
+
+#[wasm_bindgen_test]
+fn exports_answer_both_parts() {
+    assert_eq!(wasm::part1("2\n3\n4").unwrap(), "9");
+    assert_eq!(wasm::part2("2\n3\n4").unwrap(), "24");
+}
+
+#[wasm_bindgen_test]
+fn invalid_input_is_an_error() {
+    assert!(wasm::part1("2\nthree").is_err());
+    assert!(wasm::part2("\u{1F384}").is_err());
+}
+
+#[wasm_bindgen_test]
+fn unknown_part_is_an_error() {
+    assert!(aoc_common::wasm::answer::<Numbers>("2", 3).is_err());
+}
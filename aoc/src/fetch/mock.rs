@@ -0,0 +1,80 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+struct Request {
+    path: String,
+    cookie: String,
+    body: String,
+}
+
+/// Local stand-in for adventofcode.com answering fixed pages and recording the requests it received.
+pub struct MockServer {
+    address: SocketAddr,
+    received: Arc<Mutex<Vec<Request>>>,
+}
+
+fn handle(stream: TcpStream, pages: &[(&str, &str)], received: &Mutex<Vec<Request>>) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let path = request_line.split_whitespace().nth(1).unwrap_or_default().to_string();
+
+    let mut cookie = String::new();
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("cookie") {
+                cookie = value.trim().to_string();
+            } else if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or(0);
+            }
+        }
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+    let body = String::from_utf8_lossy(&body).into_owned();
+    received.lock().unwrap().push(Request { path: path.clone(), cookie, body });
+
+    let (status, body) = pages.iter().find(|(page, _)| *page == path).map_or(("404 Not Found", "Not found"), |(_, body)| ("200 OK", *body));
+    let mut stream = stream;
+    write!(stream, "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body)?;
+    stream.flush()
+}
+
+impl MockServer {
+    pub fn start(pages: Vec<(&'static str, &'static str)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let server_received = received.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let _ = handle(stream, &pages, &server_received);
+            }
+        });
+        MockServer { address, received }
+    }
+
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    pub fn requests(&self) -> Vec<String> {
+        self.received.lock().unwrap().iter().map(|request| request.path.clone()).collect()
+    }
+
+    pub fn cookies(&self) -> Vec<String> {
+        self.received.lock().unwrap().iter().map(|request| request.cookie.clone()).collect()
+    }
+
+    /// Bodies of the requests, empty for requests without one.
+    pub fn bodies(&self) -> Vec<String> {
+        self.received.lock().unwrap().iter().map(|request| request.body.clone()).collect()
+    }
+}
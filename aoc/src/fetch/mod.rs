@@ -0,0 +1,223 @@
+#[cfg(test)]
+pub mod mock;
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+
+pub const YEAR: u16 = 2023;
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Session cookie of a logged in Advent of Code account, needed to download personal inputs.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Server to download from instead of adventofcode.com, e.g. a local stand-in for testing.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+/// Directory to cache downloads in instead of the per-user cache directory.
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+const USER_AGENT: &str = concat!("aoc-2023-runner/", env!("CARGO_PKG_VERSION"), " (", env!("CARGO_PKG_AUTHORS"), ")");
+
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).timeout(Duration::from_secs(30)).build(),
+        }
+    }
+
+    /// Client configured by the environment, see [`BASE_URL_VAR`] and [`SESSION_VAR`].
+    pub fn from_env() -> Self {
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        let session = env::var(SESSION_VAR).ok().map(|session| session.trim().to_string()).filter(|session| !session.is_empty());
+        Self::new(&base_url, session)
+    }
+
+    fn request(&self, method: &str, path: &str, form: Option<&[(&str, &str)]>) -> Result<String> {
+        let url = format!("{}{}", self.base_url, path);
+        let mut request = self.agent.request(method, &url);
+        if let Some(session) = &self.session {
+            request = request.set("Cookie", &format!("session={}", session));
+        }
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(status, _)) => bail!("{} answered with status {}", url, status),
+            Err(error) => Err(error).with_context(|| format!("Could not connect to {}", url)),
+        }
+    }
+
+    fn get(&self, path: &str) -> Result<String> {
+        self.request("GET", path, None)
+    }
+
+    fn require_session(&self, action: &str) -> Result<()> {
+        if self.session.is_none() {
+            bail!("{} needs the session cookie of your account in {}", action, SESSION_VAR);
+        }
+        Ok(())
+    }
+
+    pub fn input(&self, day: u8) -> Result<String> {
+        self.require_session("Downloading inputs")?;
+        self.get(&format!("/{}/day/{}/input", YEAR, day))
+    }
+
+    /// Posts the answer of a part and returns the page telling whether it was right.
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<String> {
+        self.require_session("Submitting answers")?;
+        self.request("POST", &format!("/{}/day/{}/answer", YEAR, day), Some(&[("level", &part.to_string()), ("answer", answer)]))
+    }
+
+    /// First example of the puzzle description.
+    pub fn example(&self, day: u8) -> Result<String> {
+        let page = self.get(&format!("/{}/day/{}", YEAR, day))?;
+        extract_example(&page).with_context(|| format!("The puzzle description of day {} holds no example", day))
+    }
+}
+
+pub fn unescape_html(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(tag_start) = rest.find('<') {
+        output.push_str(&rest[..tag_start]);
+        rest = rest[tag_start..].split_once('>').map_or("", |(_, after)| after);
+    }
+    output.push_str(rest);
+    output
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Text of the first `<pre><code>` block of a puzzle page, the example input of the puzzle.
+pub fn extract_example(page: &str) -> Option<String> {
+    let (_, code) = page.split_once("<pre><code>")?;
+    let (code, _) = code.split_once("</code></pre>")?;
+    Some(unescape_html(code))
+}
+
+/// Downloads kept per day as `<dir>/<year>/dayNN/<name>.txt`, so every file is only fetched once.
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Cache { dir: dir.into() }
+    }
+
+    /// Cache in [`CACHE_DIR_VAR`], or the cache directory of the current user.
+    pub fn from_env() -> Result<Self> {
+        let dir = match env::var_os(CACHE_DIR_VAR) {
+            Some(dir) => PathBuf::from(dir),
+            None => env::var_os("XDG_CACHE_HOME")
+                .map(PathBuf::from)
+                .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))
+                .or_else(|| env::var_os("LOCALAPPDATA").map(PathBuf::from))
+                .with_context(|| format!("No cache directory found, set {}", CACHE_DIR_VAR))?
+                .join("aoc"),
+        };
+        Ok(Self::new(dir))
+    }
+
+    /// Path of the file `file_name` among the cached files of `day`.
+    pub fn day_file(&self, day: u8, file_name: &str) -> PathBuf {
+        self.dir.join(YEAR.to_string()).join(format!("day{:02}", day)).join(file_name)
+    }
+
+    pub fn path(&self, day: u8, name: &str) -> PathBuf {
+        self.day_file(day, &format!("{}.txt", name))
+    }
+
+    /// Path of the cached file, which is downloaded with `fetch` if it is missing or `refresh` is set.
+    pub fn get_or_fetch(&self, day: u8, name: &str, refresh: bool, fetch: impl FnOnce() -> Result<String>) -> Result<PathBuf> {
+        let path = self.path(day, name);
+        if refresh || !path.is_file() {
+            let content = fetch()?;
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir).with_context(|| format!("Could not create {}", dir.display()))?;
+            }
+            fs::write(&path, content).with_context(|| format!("Could not write {}", path.display()))?;
+        }
+        Ok(path)
+    }
+}
+
+pub struct Fetched {
+    pub input: PathBuf,
+    pub example: PathBuf,
+}
+
+pub fn fetch(client: &Client, cache: &Cache, day: u8, refresh: bool) -> Result<Fetched> {
+    Ok(Fetched {
+        input: cache.get_or_fetch(day, "input", refresh, || client.input(day))?,
+        example: cache.get_or_fetch(day, "example", refresh, || client.example(day))?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::mock::MockServer;
+
+    const PAGE: &str = "<article><p>For example:</p>\n<pre><code>0 3 6 9 &lt;12&gt;\n<em>1</em> 3 6\n</code></pre>\n<pre><code>other</code></pre></article>";
+
+    fn temp_cache(name: &str) -> Cache {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        Cache::new(dir)
+    }
+
+    #[test]
+    fn test_extract_example() {
+        assert_eq!(extract_example(PAGE).as_deref(), Some("0 3 6 9 <12>\n1 3 6\n"));
+        assert_eq!(extract_example("<p>No example</p>"), None);
+    }
+
+    #[test]
+    fn test_fetch_is_cached() -> Result<()> {
+        let server = MockServer::start(vec![("/2023/day/9/input", "1 2 3\n"), ("/2023/day/9", PAGE)]);
+        let client = Client::new(&server.url(), Some("secret".to_string()));
+        let cache = temp_cache("cached");
+
+        let fetched = fetch(&client, &cache, 9, false)?;
+        assert_eq!(fs::read_to_string(&fetched.input)?, "1 2 3\n");
+        assert_eq!(fs::read_to_string(&fetched.example)?, "0 3 6 9 <12>\n1 3 6\n");
+        assert!(fetched.input.ends_with("2023/day09/input.txt"));
+        assert_eq!(server.requests(), vec!["/2023/day/9/input", "/2023/day/9"]);
+        assert!(server.cookies().iter().all(|cookie| cookie == "session=secret"));
+
+        fetch(&client, &cache, 9, false)?;
+        assert_eq!(server.requests().len(), 2);
+        fetch(&client, &cache, 9, true)?;
+        assert_eq!(server.requests().len(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let server = MockServer::start(vec![]);
+        let cache = temp_cache("errors");
+
+        let error = fetch(&Client::new(&server.url(), None), &cache, 3, false).err().map(|error| error.to_string());
+        assert!(error.is_some_and(|error| error.contains(SESSION_VAR)));
+
+        let error = fetch(&Client::new(&server.url(), Some("secret".to_string())), &cache, 3, false).err().map(|error| error.to_string());
+        assert!(error.is_some_and(|error| error.contains("status 404")));
+        assert!(!cache.path(3, "input").exists());
+    }
+}
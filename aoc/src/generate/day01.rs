@@ -0,0 +1,31 @@
+use anyhow::Result;
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+
+use super::Rng;
+
+const DIGIT_WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+fn letters(rng: &mut Rng) -> String {
+    let len = rng.gen_range(1..5);
+    (0..len).map(|_| rng.gen_range(b'a'..=b'z') as char).collect()
+}
+
+/// Calibration lines mixing letters, digits and spelled out digits, each with at least one digit.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<String> {
+    let lines: Vec<String> = (0..size)
+        .map(|_| {
+            let mut tokens: Vec<String> = (0..rng.gen_range(0..7))
+                .map(|_| match rng.gen_range(0..3) {
+                    0 => letters(rng),
+                    1 => rng.gen_range(1..=9).to_string(),
+                    _ => DIGIT_WORDS.choose(rng).copied().unwrap_or("one").to_string(),
+                })
+                .collect();
+            let position = rng.gen_range(0..=tokens.len());
+            tokens.insert(position, rng.gen_range(1..=9).to_string());
+            tokens.concat()
+        })
+        .collect();
+    Ok(lines.join("\n") + "\n")
+}
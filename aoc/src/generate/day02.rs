@@ -0,0 +1,28 @@
+use anyhow::Result;
+use rand::Rng as _;
+
+use super::Rng;
+
+/// Games of up to six rounds, every round draws at least one color.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<String> {
+    let games: Vec<String> = (1..=size)
+        .map(|id| {
+            let rounds: Vec<String> = (0..rng.gen_range(1..=6))
+                .map(|_| {
+                    let mut cubes = Vec::new();
+                    for color in ["red", "green", "blue"] {
+                        if rng.gen_bool(0.6) {
+                            cubes.push(format!("{} {}", rng.gen_range(1..=20), color));
+                        }
+                    }
+                    if cubes.is_empty() {
+                        cubes.push(format!("{} blue", rng.gen_range(1..=20)));
+                    }
+                    cubes.join(", ")
+                })
+                .collect();
+            format!("Game {}: {}", id, rounds.join("; "))
+        })
+        .collect();
+    Ok(games.join("\n") + "\n")
+}
@@ -0,0 +1,33 @@
+use anyhow::Result;
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+
+use super::Rng;
+
+const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '=', '%', '-', '&'];
+
+/// Square engine schematic with numbers of up to three digits, symbols and gears scattered between dots.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<String> {
+    let rows: Vec<String> = (0..size)
+        .map(|_| {
+            let mut row = String::with_capacity(size);
+            while row.len() < size {
+                let remaining = size - row.len();
+                if rng.gen_bool(0.2) {
+                    let digits = rng.gen_range(1..=3).min(remaining);
+                    let number = rng.gen_range(10_u64.pow(digits as u32 - 1)..10_u64.pow(digits as u32));
+                    row.push_str(&number.to_string());
+                    if row.len() < size {
+                        row.push('.');
+                    }
+                } else if rng.gen_bool(0.08) {
+                    row.push(*SYMBOLS.choose(rng).unwrap_or(&'*'));
+                } else {
+                    row.push('.');
+                }
+            }
+            row
+        })
+        .collect();
+    Ok(rows.join("\n") + "\n")
+}
@@ -0,0 +1,59 @@
+use anyhow::Result;
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+
+use super::Rng;
+
+const MAPPING_NAMES: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+const NUMBER_RANGE: u64 = 4_000_000_000;
+const SEED_RANGES: usize = 10;
+
+/// Lines `(destination, source, length)` splitting the number range into `size` parts which are shuffled around.
+fn mapping(rng: &mut Rng, size: usize) -> Vec<(u64, u64, u64)> {
+    let mut cuts: Vec<u64> = (1..size).map(|_| rng.gen_range(1..NUMBER_RANGE)).collect();
+    cuts.extend([0, NUMBER_RANGE]);
+    cuts.sort_unstable();
+    cuts.dedup();
+
+    let mut sources: Vec<(u64, u64)> = cuts.windows(2).map(|cut| (cut[0], cut[1] - cut[0])).collect();
+    sources.shuffle(rng);
+    let mut destination = 0;
+    let mut lines: Vec<(u64, u64, u64)> = sources
+        .into_iter()
+        .map(|(source, len)| {
+            let line = (destination, source, len);
+            destination += len;
+            line
+        })
+        .collect();
+    lines.shuffle(rng);
+    lines
+}
+
+/// Almanac of seed ranges straddling the bounds of the mappings, every mapping is a permutation of the number range.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<String> {
+    let seeds: Vec<String> = (0..SEED_RANGES)
+        .map(|_| {
+            let start = rng.gen_range(0..NUMBER_RANGE - 1);
+            let len = rng.gen_range(1..=(NUMBER_RANGE - start).min(NUMBER_RANGE / 20));
+            format!("{} {}", start, len)
+        })
+        .collect();
+
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+    for name in MAPPING_NAMES {
+        input.push_str(&format!("\n{} map:\n", name));
+        for (destination, source, len) in mapping(rng, size) {
+            input.push_str(&format!("{} {} {}\n", destination, source, len));
+        }
+    }
+    Ok(input)
+}
@@ -0,0 +1,17 @@
+use anyhow::Result;
+use rand::Rng as _;
+
+use super::Rng;
+
+/// Race table with two digit times, every race record can be beaten.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<String> {
+    let races: Vec<(u64, u64)> = (0..size)
+        .map(|_| {
+            let time = rng.gen_range(10..100_u64);
+            (time, rng.gen_range(time..time * time / 4))
+        })
+        .collect();
+    let times: Vec<String> = races.iter().map(|(time, _)| format!("{:>4}", time)).collect();
+    let distances: Vec<String> = races.iter().map(|(_, distance)| format!("{:>4}", distance)).collect();
+    Ok(format!("Time:     {}\nDistance: {}\n", times.join(" "), distances.join(" ")))
+}
@@ -0,0 +1,26 @@
+use std::collections::BTreeSet;
+
+use anyhow::{bail, Result};
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+
+use super::Rng;
+
+const CARDS: [char; 13] = ['A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2'];
+
+/// Distinct Camel Cards hands with bids up to 1000.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<String> {
+    if size > 100_000 {
+        bail!("At most 100000 distinct hands can be generated!");
+    }
+
+    let mut seen = BTreeSet::new();
+    let mut hands = Vec::with_capacity(size);
+    while hands.len() < size {
+        let hand: String = (0..5).map(|_| *CARDS.choose(rng).unwrap_or(&'A')).collect();
+        if seen.insert(hand.clone()) {
+            hands.push(format!("{} {}", hand, rng.gen_range(1..=1000)));
+        }
+    }
+    Ok(hands.join("\n") + "\n")
+}
@@ -0,0 +1,68 @@
+use anyhow::{bail, Result};
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+
+use super::Rng;
+
+/// Every ghost takes its multiplier times the length of the move sequence to reach its `Z` node.
+const CYCLE_MULTIPLIERS: [usize; 6] = [2, 3, 5, 7, 11, 13];
+const ID_CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// Id of the `number`-th node in between a start and an end node, these never end in `A` or `Z`.
+fn intermediate_id(number: usize, width: u32) -> String {
+    let last_chars: Vec<u8> = ID_CHARS.iter().copied().filter(|char| !matches!(char, b'A' | b'Z')).collect();
+    let mut id = vec![last_chars[number % last_chars.len()]];
+    let mut rest = number / last_chars.len();
+    for _ in 1..width as usize {
+        id.push(ID_CHARS[rest % ID_CHARS.len()]);
+        rest /= ID_CHARS.len();
+    }
+    id.reverse();
+    String::from_utf8_lossy(&id).into_owned()
+}
+
+/// Network of ghost paths starting at `AAA` and other `..A` nodes, with detours branching off every path.
+///
+/// Leaving a `Z` node leads to the same nodes as leaving the start node, so every ghost runs in a cycle.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<String> {
+    let node_count = size * CYCLE_MULTIPLIERS.iter().sum::<usize>();
+    if node_count > 10_000_000 {
+        bail!("The network of {} moves would be too large!", size);
+    }
+    let width = (3..).find(|width| 34 * ID_CHARS.len().pow(width - 1) >= node_count).unwrap_or(3);
+
+    let moves: Vec<char> = (0..size).map(|_| if rng.gen_bool(0.5) { 'L' } else { 'R' }).collect();
+    let mut prefixes: Vec<String> = (b'B'..=b'Y')
+        .flat_map(|first| (b'B'..=b'Y').map(move |second| format!("{}{}", first as char, second as char)))
+        .collect();
+    prefixes.shuffle(rng);
+    prefixes[0] = "AA".to_string();
+
+    let mut next_number = 0;
+    let mut lines = Vec::new();
+    for (prefix, multiplier) in prefixes.iter().zip(CYCLE_MULTIPLIERS) {
+        let (start, end) = match prefix.as_str() {
+            "AA" => ("AAA".to_string(), "ZZZ".to_string()),
+            prefix => (format!("{}A", prefix), format!("{}Z", prefix)),
+        };
+        let steps = multiplier * size;
+        let mut path = vec![start];
+        path.extend((1..steps).map(|number| intermediate_id(next_number + number, width)));
+        next_number += steps;
+
+        let mut targets = Vec::with_capacity(steps);
+        for (step, direction) in moves.iter().cycle().take(steps).enumerate() {
+            let next = if step + 1 < steps { path[step + 1].clone() } else { end.clone() };
+            let detour = path.choose(rng).cloned().unwrap_or_else(|| next.clone());
+            targets.push(if *direction == 'L' { (next, detour) } else { (detour, next) });
+        }
+        let (start_left, start_right) = targets[0].clone();
+        for (id, (left, right)) in path.iter().zip(targets) {
+            lines.push(format!("{} = ({}, {})", id, left, right));
+        }
+        lines.push(format!("{} = ({}, {})", end, start_left, start_right));
+    }
+    lines.shuffle(rng);
+
+    Ok(format!("{}\n\n{}\n", moves.into_iter().collect::<String>(), lines.join("\n")))
+}
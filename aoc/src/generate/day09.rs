@@ -0,0 +1,20 @@
+use anyhow::Result;
+use rand::Rng as _;
+
+use super::Rng;
+
+const VALUE_COUNT: i64 = 21;
+
+/// OASIS sequences of polynomials of up to fifth degree, so the differences always reach zero.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<String> {
+    let lines: Vec<String> = (0..size)
+        .map(|_| {
+            let coefficients: Vec<i64> = (0..=rng.gen_range(0..=5)).map(|_| rng.gen_range(-10..=10)).collect();
+            let values: Vec<String> = (0..VALUE_COUNT)
+                .map(|x| coefficients.iter().rev().fold(0, |value, coefficient| value * x + coefficient).to_string())
+                .collect();
+            values.join(" ")
+        })
+        .collect();
+    Ok(lines.join("\n") + "\n")
+}
@@ -0,0 +1,121 @@
+use anyhow::Result;
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+
+use super::Rng;
+
+const NORTH: u8 = 1;
+const EAST: u8 = 2;
+const SOUTH: u8 = 4;
+const WEST: u8 = 8;
+
+fn pipe(connections: u8) -> char {
+    match connections {
+        x if x == NORTH | SOUTH => '|',
+        x if x == EAST | WEST => '-',
+        x if x == NORTH | EAST => 'L',
+        x if x == NORTH | WEST => 'J',
+        x if x == SOUTH | WEST => '7',
+        x if x == SOUTH | EAST => 'F',
+        _ => '.',
+    }
+}
+
+type Cell = (usize, usize);
+
+/// Randomly grown connected set of cells of a `size` by `size` grid and the edges of a spanning tree of them.
+fn grow_tree(rng: &mut Rng, size: usize) -> (Vec<Cell>, Vec<(Cell, Cell)>) {
+    let target = (size * size * 3 / 5).max(1);
+    let start = (rng.gen_range(0..size), rng.gen_range(0..size));
+    let mut visited = vec![vec![false; size]; size];
+    visited[start.0][start.1] = true;
+    let mut cells = vec![start];
+    let mut edges = Vec::new();
+
+    while cells.len() < target {
+        let (y, x) = *cells.choose(rng).unwrap_or(&start);
+        let neighbors = [
+            y.checked_sub(1).map(|y| (y, x)),
+            Some((y + 1, x)).filter(|(y, _)| *y < size),
+            x.checked_sub(1).map(|x| (y, x)),
+            Some((y, x + 1)).filter(|(_, x)| *x < size),
+        ];
+        if let Some(&Some((next_y, next_x))) = neighbors.choose(rng) {
+            if !visited[next_y][next_x] {
+                visited[next_y][next_x] = true;
+                cells.push((next_y, next_x));
+                edges.push(((y, x), (next_y, next_x)));
+            }
+        }
+    }
+    (cells, edges)
+}
+
+fn toggle_horizontal_link(connections: &mut [Vec<u8>], y: usize, x: usize) {
+    connections[y][x] ^= EAST;
+    connections[y][x + 1] ^= WEST;
+}
+
+fn toggle_vertical_link(connections: &mut [Vec<u8>], y: usize, x: usize) {
+    connections[y][x] ^= SOUTH;
+    connections[y + 1][x] ^= NORTH;
+}
+
+/// Pipe maze of three times `size` tiles in both directions holding exactly one loop through the starting position.
+///
+/// Every cell of a spanning tree becomes a ring of pipes around one enclosed tile and the rings of neighboring
+/// tree cells are joined, which turns the tree into a single loop around it.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<String> {
+    let (cells, edges) = grow_tree(rng, size);
+    let mut connections = vec![vec![0_u8; 3 * size]; 3 * size];
+    for (y, x) in cells.iter().map(|(y, x)| (3 * y, 3 * x)) {
+        for offset in 0..2 {
+            toggle_horizontal_link(&mut connections, y, x + offset);
+            toggle_horizontal_link(&mut connections, y + 2, x + offset);
+            toggle_vertical_link(&mut connections, y + offset, x);
+            toggle_vertical_link(&mut connections, y + offset, x + 2);
+        }
+    }
+    for ((from_y, from_x), (to_y, to_x)) in edges {
+        let (y, x) = (3 * from_y.min(to_y), 3 * from_x.min(to_x));
+        if from_y == to_y {
+            toggle_vertical_link(&mut connections, y, x + 2);
+            toggle_vertical_link(&mut connections, y, x + 3);
+            toggle_horizontal_link(&mut connections, y, x + 2);
+            toggle_horizontal_link(&mut connections, y + 1, x + 2);
+        } else {
+            toggle_horizontal_link(&mut connections, y + 2, x);
+            toggle_horizontal_link(&mut connections, y + 3, x);
+            toggle_vertical_link(&mut connections, y + 2, x);
+            toggle_vertical_link(&mut connections, y + 2, x + 1);
+        }
+    }
+
+    let loop_tiles: Vec<(usize, usize)> = (0..3 * size)
+        .flat_map(|y| (0..3 * size).map(move |x| (y, x)))
+        .filter(|(y, x)| connections[*y][*x] != 0)
+        .collect();
+    let start = *loop_tiles.choose(rng).unwrap_or(&(0, 0));
+
+    let mut tiles: Vec<Vec<char>> = connections
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|connection| match connection {
+                    0 => *['.', '.', '|', '-', 'L', 'J', '7', 'F'].choose(rng).unwrap_or(&'.'),
+                    connection => pipe(*connection),
+                })
+                .collect()
+        })
+        .collect();
+    // Junk next to the start could look like another pipe leading into it.
+    for (y, x) in [(start.0.wrapping_sub(1), start.1), (start.0 + 1, start.1), (start.0, start.1.wrapping_sub(1)), (start.0, start.1 + 1)] {
+        if connections.get(y).and_then(|row| row.get(x)) == Some(&0) {
+            tiles[y][x] = '.';
+        }
+    }
+    tiles[start.0][start.1] = 'S';
+
+    let rows: Vec<String> = tiles.into_iter().map(|row| row.into_iter().collect()).collect();
+    Ok(rows.join("\n") + "\n")
+}
@@ -0,0 +1,84 @@
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+
+use anyhow::{bail, Result};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+/// Random number generator of all generators, seeded so the same seed always produces the same input.
+pub type Rng = ChaCha8Rng;
+
+pub struct Generator {
+    pub day: u8,
+    /// Size used if none is requested, close to the size of the real puzzle inputs.
+    pub default_size: usize,
+    /// Meaning of the size for the input of this day.
+    pub size_unit: &'static str,
+    generate: fn(&mut Rng, usize) -> Result<String>,
+}
+
+pub static GENERATORS: [Generator; 10] = [
+    Generator { day: 1, default_size: 1000, size_unit: "calibration lines", generate: day01::generate },
+    Generator { day: 2, default_size: 100, size_unit: "games", generate: day02::generate },
+    Generator { day: 3, default_size: 140, size_unit: "rows and columns of the schematic", generate: day03::generate },
+    Generator { day: 4, default_size: 200, size_unit: "scratchcards", generate: day04::generate },
+    Generator { day: 5, default_size: 40, size_unit: "ranges per mapping", generate: day05::generate },
+    Generator { day: 6, default_size: 4, size_unit: "races", generate: day06::generate },
+    Generator { day: 7, default_size: 1000, size_unit: "hands", generate: day07::generate },
+    Generator { day: 8, default_size: 280, size_unit: "moves in the sequence", generate: day08::generate },
+    Generator { day: 9, default_size: 200, size_unit: "sequences", generate: day09::generate },
+    Generator { day: 10, default_size: 47, size_unit: "thirds of the width and height of the maze", generate: day10::generate },
+];
+
+pub fn find_generator(day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|generator| generator.day == day)
+}
+
+impl Generator {
+    pub fn generate(&self, size: Option<usize>, seed: u64) -> Result<String> {
+        let size = size.unwrap_or(self.default_size);
+        if size == 0 {
+            bail!("The size of a generated input has to be at least 1!");
+        }
+        let mut rng = Rng::seed_from_u64(seed);
+        (self.generate)(&mut rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::find_solvers;
+
+    #[test]
+    fn test_generation_is_deterministic() -> Result<()> {
+        for generator in GENERATORS.iter() {
+            assert_eq!(generator.generate(Some(8), 7)?, generator.generate(Some(8), 7)?);
+            assert_ne!(generator.generate(Some(8), 7)?, generator.generate(Some(8), 8)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_generated_inputs_are_solvable() -> Result<()> {
+        for generator in GENERATORS.iter() {
+            for seed in 0..10 {
+                let input = generator.generate(Some(3), seed)?;
+                for solver in find_solvers(generator.day, None) {
+                    (solver.solve)(&input).map_err(|error| {
+                        anyhow::anyhow!("Day {} part {} with seed {}: {}", solver.day, solver.part, seed, error)
+                    })?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
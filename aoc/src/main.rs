@@ -0,0 +1,633 @@
+mod fetch;
+mod generate;
+mod profile;
+mod registry;
+mod scaffold;
+mod schedule;
+mod submit;
+mod verify;
+mod watch;
+
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+use anyhow::{bail, Context, Result};
+use aoc_common::input::{default_input_path, find_in_ancestors, load_input, open_input, read_file};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+
+use crate::fetch::{fetch, Cache, Client};
+use crate::generate::{find_generator, GENERATORS};
+use crate::profile::{profile_solver, Profile};
+use crate::registry::{find_solvers, find_stream_solvers, Solver, StreamSolver, SOLVERS, STREAM_SOLVERS};
+use crate::scaffold::{create_day, find_workspace_root};
+use crate::schedule::run_parallel;
+use crate::submit::{submit, Verdict};
+use crate::verify::{verify, Answers, Check, Status, ANSWERS_FILE};
+use crate::watch::{changed_files, diff_answers, run_examples, run_input, snapshot, AnswerChange};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution of a single day or of all registered days
+    Run(RunArgs),
+    /// Check the solutions against the answers recorded for their inputs
+    Verify(VerifyArgs),
+    /// Generate a random puzzle input of any size for a day
+    Gen(GenArgs),
+    /// Download the input and the example of a day into the local cache
+    Fetch(FetchArgs),
+    /// Create the crate of a new day and register it in the workspace and the runner
+    New(NewArgs),
+    /// Measure the time and memory of every phase of the solutions
+    Profile(ProfileArgs),
+    /// Submit the answer of a part, unless it is known to be wrong from earlier submissions
+    Submit(SubmitArgs),
+    /// Re-run the examples and the input of a day whenever its sources or resources change
+    Watch(WatchArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to run
+    #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+    /// Part of the day to run, both parts are run if omitted
+    #[arg(long, requires = "day")]
+    part: Option<u8>,
+    /// Input file to use instead of the day's default input, `-` reads from stdin
+    #[arg(long, requires = "day")]
+    input: Option<String>,
+    /// Run every registered day and part
+    #[arg(long)]
+    all: bool,
+    /// Read the input line by line instead of loading it into memory, only some days support this
+    #[arg(long, conflicts_with = "parallel")]
+    stream: bool,
+    /// Solve all requested days and parts concurrently
+    #[arg(long)]
+    parallel: bool,
+    /// Threads solving the parts concurrently, including the solutions' own parallel work, defaults to one per CPU
+    #[arg(long, requires = "parallel")]
+    threads: Option<usize>,
+    /// Output format of the results
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// Human readable table
+    Table,
+    /// JSON array with one object per day and part
+    Json,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Day to verify, all registered days are verified if omitted
+    #[arg(long)]
+    day: Option<u8>,
+    /// Answers file to use instead of the `answers.toml` of the workspace
+    #[arg(long)]
+    answers: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct GenArgs {
+    /// Day to generate an input for
+    #[arg(long)]
+    day: u8,
+    /// Size of the input, what it counts depends on the day, defaults to about the size of a real input
+    #[arg(long)]
+    size: Option<usize>,
+    /// Seed of the random number generator, the same seed always generates the same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// File to write the input to instead of stdout
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Day to download
+    #[arg(long)]
+    day: u8,
+    /// Download again even if the files are cached already
+    #[arg(long)]
+    refresh: bool,
+    /// Directory to copy the files to as `input.txt` and `test_input.txt`, e.g. the day's `resources`
+    #[arg(long)]
+    output: Option<PathBuf>,
+    /// Overwrite existing non-empty files in the output directory
+    #[arg(long, requires = "output")]
+    force: bool,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day to create the crate for
+    #[arg(long)]
+    day: u8,
+}
+
+#[derive(Args)]
+struct ProfileArgs {
+    /// Day to profile
+    #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+    /// Part of the day to profile, both parts are profiled if omitted
+    #[arg(long, requires = "day")]
+    part: Option<u8>,
+    /// Input file to use instead of the day's default input, `-` reads from stdin
+    #[arg(long, requires = "day")]
+    input: Option<String>,
+    /// Profile every registered day and part
+    #[arg(long)]
+    all: bool,
+    /// File to write the sampled call stacks to, in the folded format of flamegraph tools
+    #[arg(long)]
+    folded: Option<PathBuf>,
+    /// Samples of the call stacks per second
+    #[arg(long, default_value_t = 1000, requires = "folded")]
+    frequency: i32,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Day to submit the answer for
+    #[arg(long)]
+    day: u8,
+    /// Part to submit the answer for
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Input file to solve instead of the day's default input, `-` reads from stdin
+    #[arg(long, conflicts_with = "answer")]
+    input: Option<String>,
+    /// Answer to submit instead of the one computed by the solution
+    #[arg(long)]
+    answer: Option<String>,
+}
+
+#[derive(Args)]
+struct WatchArgs {
+    /// Day to watch
+    #[arg(long)]
+    day: u8,
+    /// Input file to use instead of the day's default input, it is watched as well
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Cargo profile to build the tests and the runner with
+    #[arg(long, default_value = "release")]
+    profile: String,
+    /// Milliseconds between two checks for changed files
+    #[arg(long, default_value_t = 500)]
+    interval: u64,
+}
+
+#[derive(Serialize)]
+struct RunResult {
+    day: u8,
+    part: u8,
+    answer: String,
+    #[serde(rename = "parse_time_ns", serialize_with = "serialize_nanos")]
+    parse_time: Duration,
+    #[serde(rename = "solve_time_ns", serialize_with = "serialize_nanos")]
+    solve_time: Duration,
+    input: String,
+}
+
+fn serialize_nanos<S: serde::Serializer>(duration: &Duration, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+/// Loads the input of `day` and returns it together with a description of where it was read from.
+fn load_named_input(day: u8, source: Option<&str>) -> Result<(String, String)> {
+    match source {
+        Some(source) => Ok((source.to_string(), load_input(day, Some(source))?)),
+        None => {
+            let path = default_input_path(day)?;
+            Ok((path.display().to_string(), read_file(&path)?))
+        }
+    }
+}
+
+fn run_solver(solver: &Solver, input_name: &str, input: &str) -> Result<RunResult> {
+    let solved = (solver.solve)(input)
+        .with_context(|| format!("Failed to solve day {} part {}", solver.day, solver.part))?;
+
+    Ok(RunResult {
+        day: solver.day,
+        part: solver.part,
+        answer: solved.answer,
+        parse_time: solved.parse_time,
+        solve_time: solved.solve_time,
+        input: input_name.to_string(),
+    })
+}
+
+fn print_results(results: &[RunResult]) {
+    println!("{:>3}  {:>4}  {:>20}  {:>14}", "Day", "Part", "Answer", "Elapsed");
+    for result in results {
+        println!(
+            "{:>3}  {:>4}  {:>20}  {:>14}",
+            result.day,
+            result.part,
+            result.answer,
+            format!("{:?}", result.parse_time + result.solve_time)
+        );
+    }
+}
+
+fn print_results_json(results: &[RunResult]) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(results)?);
+    Ok(())
+}
+
+fn input_name(day: u8, source: Option<&str>) -> Result<String> {
+    match source {
+        Some(source) => Ok(source.to_string()),
+        None => Ok(default_input_path(day)?.display().to_string()),
+    }
+}
+
+fn run_stream(args: &RunArgs) -> Result<Vec<RunResult>> {
+    let solvers: Vec<&StreamSolver> = match args.day {
+        Some(day) => find_stream_solvers(day, args.part),
+        None => STREAM_SOLVERS.iter().collect(),
+    };
+    if solvers.is_empty() {
+        let days: Vec<String> = STREAM_SOLVERS.iter().filter(|solver| solver.part == 1).map(|solver| solver.day.to_string()).collect();
+        bail!("No streaming solution registered for the requested day and part, available days are {}", days.join(", "));
+    }
+    if args.input.as_deref() == Some("-") && solvers.len() > 1 {
+        bail!("A streamed stdin can only be read by a single part, pass --part");
+    }
+
+    // Every part reads the input again, so it never has to be held in memory.
+    let mut results = Vec::new();
+    for solver in solvers {
+        let mut reader = open_input(solver.day, args.input.as_deref())?;
+        let solved = (solver.solve)(&mut reader)
+            .with_context(|| format!("Failed to solve day {} part {}", solver.day, solver.part))?;
+        results.push(RunResult {
+            day: solver.day,
+            part: solver.part,
+            answer: solved.answer,
+            parse_time: solved.parse_time,
+            solve_time: solved.solve_time,
+            input: input_name(solver.day, args.input.as_deref())?,
+        });
+    }
+    Ok(results)
+}
+
+fn run(args: RunArgs) -> Result<()> {
+    let now = Instant::now();
+    let results = if args.stream { run_stream(&args)? } else { run_in_memory(&args)? };
+    let elapsed = now.elapsed();
+    match args.format {
+        Format::Table => print_results(&results),
+        Format::Json => print_results_json(&results)?,
+    }
+    if args.parallel && matches!(args.format, Format::Table) {
+        let total: Duration = results.iter().map(|result| result.parse_time + result.solve_time).sum();
+        println!();
+        println!("Solved {} parts in {:?}, {:?} when run one after another", results.len(), elapsed, total);
+    }
+    Ok(())
+}
+
+fn run_in_memory(args: &RunArgs) -> Result<Vec<RunResult>> {
+    let solvers: Vec<&Solver> = match args.day {
+        Some(day) => find_solvers(day, args.part),
+        None => SOLVERS.iter().collect(),
+    };
+    if solvers.is_empty() {
+        bail!("No solution registered for the requested day and part!");
+    }
+
+    if args.parallel {
+        let mut inputs: BTreeMap<u8, (String, String)> = BTreeMap::new();
+        for solver in &solvers {
+            if let Entry::Vacant(entry) = inputs.entry(solver.day) {
+                entry.insert(load_named_input(solver.day, args.input.as_deref())?);
+            }
+        }
+        let results = run_parallel(&solvers, args.threads, |solver| {
+            let (input_name, input) = &inputs[&solver.day];
+            run_solver(solver, input_name, input)
+        })?;
+        // The first failed part in the order of the days is reported, not the one failing first.
+        return results.into_iter().collect();
+    }
+
+    let mut results = Vec::new();
+    let mut inputs: BTreeMap<u8, (String, String)> = BTreeMap::new();
+    for solver in solvers {
+        let (input_name, input) = match inputs.entry(solver.day) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(load_named_input(solver.day, args.input.as_deref())?),
+        };
+        results.push(run_solver(solver, input_name, input)?);
+    }
+    Ok(results)
+}
+
+fn format_bytes(bytes: usize) -> String {
+    match bytes {
+        bytes if bytes >= 1 << 20 => format!("{:.1} MiB", bytes as f64 / (1 << 20) as f64),
+        bytes if bytes >= 1 << 10 => format!("{:.1} KiB", bytes as f64 / (1 << 10) as f64),
+        bytes => format!("{} B", bytes),
+    }
+}
+
+fn print_profiles(profiles: &[Profile]) {
+    println!(
+        "{:>3}  {:>4}  {:>20}  {:<5}  {:>14}  {:>11}  {:>11}  {:>11}",
+        "Day", "Part", "Answer", "Phase", "Elapsed", "Allocations", "Allocated", "Peak heap"
+    );
+    for profile in profiles {
+        for (index, phase) in profile.phases.iter().enumerate() {
+            println!(
+                "{:>3}  {:>4}  {:>20}  {:<5}  {:>14}  {:>11}  {:>11}  {:>11}",
+                profile.day,
+                profile.part,
+                if index == 0 { profile.answer.as_str() } else { "" },
+                phase.name,
+                format!("{:?}", phase.time),
+                phase.allocations,
+                format_bytes(phase.allocated_bytes),
+                format_bytes(phase.peak_bytes)
+            );
+        }
+    }
+}
+
+fn profile(args: ProfileArgs) -> Result<()> {
+    let solvers: Vec<&Solver> = match args.day {
+        Some(day) => find_solvers(day, args.part),
+        None => SOLVERS.iter().collect(),
+    };
+    if solvers.is_empty() {
+        bail!("No solution registered for the requested day and part!");
+    }
+
+    // Stdin can only be read once, every other input is read again for each part to measure the reading.
+    let stdin_input = match args.input.as_deref() {
+        Some("-") => Some(load_input(0, Some("-"))?),
+        _ => None,
+    };
+    let sample_frequency = args.folded.as_ref().map(|_| args.frequency);
+    let mut profiles = Vec::new();
+    for solver in solvers {
+        let read_input = || match &stdin_input {
+            Some(input) => Ok(input.clone()),
+            None => Ok(load_named_input(solver.day, args.input.as_deref())?.1),
+        };
+        profiles.push(profile_solver(solver, read_input, sample_frequency)?);
+    }
+    print_profiles(&profiles);
+
+    if let Some(path) = args.folded {
+        let lines: Vec<&str> = profiles.iter().flat_map(|profile| profile.folded_stacks.iter().map(String::as_str)).collect();
+        fs::write(&path, lines.join("\n") + "\n").with_context(|| format!("Could not write {}", path.display()))?;
+        println!();
+        println!("Wrote {} sampled stacks to {}", lines.len(), path.display());
+    }
+    Ok(())
+}
+
+fn print_checks(checks: &[Check]) {
+    println!("{:>3}  {:>4}  {:<16}  {:<7}  Details", "Day", "Part", "Input", "Status");
+    for check in checks {
+        let (status, details) = match &check.status {
+            Status::Pass => ("PASS", String::new()),
+            Status::Fail { actual } => (
+                "FAIL",
+                format!("expected {}, got {}", check.expected.as_deref().unwrap_or_default(), actual),
+            ),
+            Status::Missing { reason } => ("MISSING", reason.clone()),
+        };
+        let line = format!("{:>3}  {:>4}  {:<16}  {:<7}  {}", check.day, check.part, check.input, status, details);
+        println!("{}", line.trim_end());
+    }
+}
+
+fn verify_answers(args: VerifyArgs) -> Result<()> {
+    let solvers: Vec<&Solver> = match args.day {
+        Some(day) => find_solvers(day, None),
+        None => SOLVERS.iter().collect(),
+    };
+    if solvers.is_empty() {
+        bail!("No solution registered for the requested day!");
+    }
+
+    let answers_path = match args.answers {
+        Some(path) => path,
+        None => find_in_ancestors(Path::new(ANSWERS_FILE))?,
+    };
+    let answers = Answers::load(&answers_path)?;
+
+    let checks = verify(&solvers, &answers);
+    print_checks(&checks);
+
+    let passed = checks.iter().filter(|check| matches!(check.status, Status::Pass)).count();
+    let failed = checks.iter().filter(|check| matches!(check.status, Status::Fail { .. })).count();
+    let missing = checks.iter().filter(|check| matches!(check.status, Status::Missing { .. })).count();
+    println!();
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    if failed > 0 {
+        bail!("{} answers did not match the recorded ones!", failed);
+    }
+    Ok(())
+}
+
+fn generate_input(args: GenArgs) -> Result<()> {
+    let Some(generator) = find_generator(args.day) else {
+        let days: Vec<String> = GENERATORS.iter().map(|generator| generator.day.to_string()).collect();
+        bail!("No generator registered for day {}, available days are {}", args.day, days.join(", "));
+    };
+
+    let input = generator
+        .generate(args.size, args.seed)
+        .with_context(|| format!("Failed to generate {} {} for day {}", args.size.unwrap_or(generator.default_size), generator.size_unit, args.day))?;
+    match args.output {
+        Some(path) => fs::write(&path, input).with_context(|| format!("Could not write {}", path.display()))?,
+        None => print!("{}", input),
+    }
+    Ok(())
+}
+
+fn fetch_day(args: FetchArgs) -> Result<()> {
+    if !(1..=25).contains(&args.day) {
+        bail!("There are only puzzles for days 1 to 25!");
+    }
+    let fetched = fetch(&Client::from_env(), &Cache::from_env()?, args.day, args.refresh)?;
+    println!("Input:   {}", fetched.input.display());
+    println!("Example: {}", fetched.example.display());
+
+    if let Some(output) = args.output {
+        let copies = [(fetched.input, output.join("input.txt")), (fetched.example, output.join("test_input.txt"))];
+        // Empty files are the placeholders of `aoc new` and are replaced without asking.
+        let is_filled = |path: &Path| fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0);
+        if let Some((_, existing)) = copies.iter().find(|(_, to)| is_filled(to) && !args.force) {
+            bail!("{} exists already, pass --force to overwrite it", existing.display());
+        }
+        fs::create_dir_all(&output).with_context(|| format!("Could not create {}", output.display()))?;
+        for (from, to) in copies {
+            fs::copy(&from, &to).with_context(|| format!("Could not copy {} to {}", from.display(), to.display()))?;
+            println!("Wrote {}", to.display());
+        }
+    }
+    Ok(())
+}
+
+fn new_day(args: NewArgs) -> Result<()> {
+    if !(1..=25).contains(&args.day) {
+        bail!("There are only puzzles for days 1 to 25!");
+    }
+    let root = find_workspace_root()?;
+    for path in create_day(&root, args.day)? {
+        println!("Created {}", path.strip_prefix(&root).unwrap_or(&path).display());
+    }
+    println!("Registered day{:02} in the workspace and the runner", args.day);
+    Ok(())
+}
+
+fn submit_answer(args: SubmitArgs) -> Result<()> {
+    let answer = match args.answer {
+        Some(answer) => answer.trim().to_string(),
+        None => {
+            let Some(solver) = find_solvers(args.day, Some(args.part)).into_iter().next() else {
+                bail!("No solution registered for day {} part {}, pass the answer with --answer", args.day, args.part);
+            };
+            let (input_name, input) = load_named_input(args.day, args.input.as_deref())?;
+            run_solver(solver, &input_name, &input)?.answer
+        }
+    };
+    println!("Submitting {} for day {} part {}", answer, args.day, args.part);
+
+    let response = submit(&Client::from_env(), &Cache::from_env()?, args.day, args.part, &answer, SystemTime::now())?;
+    println!("{}", response.message);
+    match response.verdict {
+        Verdict::Correct | Verdict::AlreadySolved => Ok(()),
+        verdict => bail!("The answer {} was {}", answer, verdict),
+    }
+}
+
+/// Runs the examples and the input of the watched day and prints how the answers changed since the last run.
+fn run_watched(root: &Path, args: &WatchArgs, input: Option<&str>, answers: &mut BTreeMap<u8, String>) -> Result<()> {
+    println!("Running the examples of day {}", args.day);
+    if !run_examples(root, args.day, &args.profile)? {
+        println!("The examples failed");
+        return Ok(());
+    }
+    println!("Running the input of day {}", args.day);
+    let current = match run_input(root, args.day, input, &args.profile) {
+        Ok(current) => current,
+        Err(error) => {
+            println!("{:#}", error);
+            return Ok(());
+        }
+    };
+    for (part, answer, change) in diff_answers(answers, &current) {
+        let answer = answer.unwrap_or_default();
+        match change {
+            AnswerChange::New => println!("Part {}: {}", part, answer),
+            AnswerChange::Unchanged => println!("Part {}: {} (unchanged)", part, answer),
+            AnswerChange::Changed { previous } => println!("Part {}: {} (was {})", part, answer, previous),
+            AnswerChange::Removed { previous } => println!("Part {}: no answer (was {})", part, previous),
+        }
+    }
+    *answers = current;
+    Ok(())
+}
+
+fn watch_day(args: WatchArgs) -> Result<()> {
+    let root = find_workspace_root()?;
+    let day_dir = root.join(format!("day{:02}", args.day));
+    if !day_dir.join("Cargo.toml").exists() {
+        bail!("There is no crate for day {} at {}", args.day, day_dir.display());
+    }
+    // Cargo runs in the workspace root, so a relative input has to be resolved first.
+    let input = args
+        .input
+        .as_ref()
+        .map(|path| fs::canonicalize(path).with_context(|| format!("Could not find {}", path.display())))
+        .transpose()?;
+    let mut watched = vec![day_dir.join("src"), day_dir.join("resources")];
+    watched.extend(input.clone());
+    let input = input.as_ref().map(|path| path.to_string_lossy().into_owned());
+
+    let interval = Duration::from_millis(args.interval);
+    let mut answers = BTreeMap::new();
+    let mut files = snapshot(&watched)?;
+    run_watched(&root, &args, input.as_deref(), &mut answers)?;
+    loop {
+        println!();
+        println!("Watching {} for changes", watched.iter().map(|path| path.display().to_string()).collect::<Vec<_>>().join(", "));
+        let changed = loop {
+            thread::sleep(interval);
+            let current = snapshot(&watched)?;
+            let changed = changed_files(&files, &current);
+            if !changed.is_empty() {
+                // Wait for saves touching several files to finish before building.
+                thread::sleep(interval);
+                files = snapshot(&watched)?;
+                break changed;
+            }
+        };
+        for path in changed {
+            println!("Changed {}", path.strip_prefix(&root).unwrap_or(&path).display());
+        }
+        run_watched(&root, &args, input.as_deref(), &mut answers)?;
+    }
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify_answers(args),
+        Command::Gen(args) => generate_input(args),
+        Command::Fetch(args) => fetch_day(args),
+        Command::New(args) => new_day(args),
+        Command::Profile(args) => profile(args),
+        Command::Submit(args) => submit_answer(args),
+        Command::Watch(args) => watch_day(args),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_result_fields() -> Result<()> {
+        let result = RunResult {
+            day: 7,
+            part: 2,
+            answer: "5905".to_string(),
+            parse_time: Duration::from_micros(3),
+            solve_time: Duration::from_nanos(42),
+            input: "day07/resources/test_input.txt".to_string(),
+        };
+        assert_eq!(
+            serde_json::to_string(&result)?,
+            r#"{"day":7,"part":2,"answer":"5905","parse_time_ns":3000,"solve_time_ns":42,"input":"day07/resources/test_input.txt"}"#
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+mod alloc;
+#[cfg(unix)]
+mod sampler;
+
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use aoc_common::{Phase, PhaseObserver};
+
+use crate::profile::alloc::{set_counting, snapshot, start_measurement, AllocationSnapshot};
+use crate::registry::Solver;
+
+/// Measurements of one phase, the peak counts the heap bytes in use above those live when the phase started.
+pub struct PhaseProfile {
+    pub name: &'static str,
+    pub time: Duration,
+    pub allocations: usize,
+    pub allocated_bytes: usize,
+    pub peak_bytes: usize,
+}
+
+pub struct Profile {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub phases: Vec<PhaseProfile>,
+    /// Sampled call stacks in the folded format, empty if sampling was not requested.
+    pub folded_stacks: Vec<String>,
+}
+
+fn measure(name: &'static str, time: Duration, start: &AllocationSnapshot, end: &AllocationSnapshot) -> PhaseProfile {
+    PhaseProfile {
+        name,
+        time,
+        allocations: end.allocations - start.allocations,
+        allocated_bytes: end.allocated_bytes - start.allocated_bytes,
+        peak_bytes: end.peak_bytes.saturating_sub(start.live_bytes),
+    }
+}
+
+#[derive(Default)]
+struct AllocationObserver {
+    start: AllocationSnapshot,
+    parse: (AllocationSnapshot, AllocationSnapshot),
+    solve: (AllocationSnapshot, AllocationSnapshot),
+}
+
+impl PhaseObserver for AllocationObserver {
+    fn enter(&mut self, _phase: Phase) {
+        self.start = start_measurement();
+    }
+
+    fn exit(&mut self, phase: Phase) {
+        let measured = (self.start, snapshot());
+        match phase {
+            Phase::Parse => self.parse = measured,
+            Phase::Solve => self.solve = measured,
+        }
+    }
+}
+
+#[cfg(unix)]
+fn sample<T>(frequency: Option<i32>, root: &str, run: impl FnOnce() -> Result<T>) -> Result<(T, Vec<String>)> {
+    let Some(frequency) = frequency else {
+        return Ok((run()?, Vec::new()));
+    };
+    let sampler = sampler::Sampler::start(frequency)?;
+    let result = run()?;
+    Ok((result, sampler.finish(root)?))
+}
+
+#[cfg(not(unix))]
+fn sample<T>(frequency: Option<i32>, _root: &str, run: impl FnOnce() -> Result<T>) -> Result<(T, Vec<String>)> {
+    if frequency.is_some() {
+        anyhow::bail!("Sampling call stacks is only supported on Unix");
+    }
+    Ok((run()?, Vec::new()))
+}
+
+/// Reads the input and solves the part while measuring every phase, sampling the call stacks
+/// `sample_frequency` times per second if given.
+pub fn profile_solver(solver: &Solver, read_input: impl FnOnce() -> Result<String>, sample_frequency: Option<i32>) -> Result<Profile> {
+    set_counting(true);
+    let root = format!("day{:02}_part{}", solver.day, solver.part);
+    let ((read, solved, observer), folded_stacks) = sample(sample_frequency, &root, || {
+        let start = start_measurement();
+        let now = Instant::now();
+        let input = read_input()?;
+        let read = measure("read", now.elapsed(), &start, &snapshot());
+
+        let mut observer = AllocationObserver::default();
+        let solved = (solver.solve_observed)(&input, &mut observer)
+            .with_context(|| format!("Failed to solve day {} part {}", solver.day, solver.part))?;
+        Ok((read, solved, observer))
+    })?;
+
+    Ok(Profile {
+        day: solver.day,
+        part: solver.part,
+        answer: solved.answer,
+        phases: vec![
+            read,
+            measure("parse", solved.parse_time, &observer.parse.0, &observer.parse.1),
+            measure("solve", solved.solve_time, &observer.solve.0, &observer.solve.1),
+        ],
+        folded_stacks,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::find_solvers;
+
+    #[test]
+    fn test_profile_solver() -> Result<()> {
+        let solver = find_solvers(7, Some(1))[0];
+        let input = "32T3K 765\nT55J5 684\nKK677 28\nKTJJT 220\nQQQJA 483\n";
+        let profile = profile_solver(solver, || Ok(input.to_string()), None)?;
+        assert_eq!(profile.answer, "6440");
+        let names: Vec<&str> = profile.phases.iter().map(|phase| phase.name).collect();
+        assert_eq!(names, vec!["read", "parse", "solve"]);
+        assert!(profile.phases[0].allocated_bytes >= input.len());
+        assert!(profile.phases[1].allocations > 0);
+        assert!(profile.folded_stacks.is_empty());
+        Ok(())
+    }
+}
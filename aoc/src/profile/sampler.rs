@@ -0,0 +1,39 @@
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
+use pprof::{ProfilerGuard, ProfilerGuardBuilder};
+
+/// Samples the call stacks of all threads until [`Sampler::finish`] is called.
+pub struct Sampler {
+    guard: ProfilerGuard<'static>,
+}
+
+impl Sampler {
+    pub fn start(frequency: i32) -> Result<Self> {
+        let guard = ProfilerGuardBuilder::default()
+            .frequency(frequency)
+            .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+            .build()
+            .context("Could not start sampling the call stacks")?;
+        Ok(Sampler { guard })
+    }
+
+    /// Sampled stacks in the folded format of `flamegraph.pl` and inferno, each stack below a root frame named `root`.
+    pub fn finish(self, root: &str) -> Result<Vec<String>> {
+        let report = self.guard.report().build().context("Could not resolve the sampled call stacks")?;
+        let mut lines: Vec<String> = report
+            .data
+            .iter()
+            .map(|(frames, count)| {
+                let mut line = root.to_string();
+                for symbol in frames.frames.iter().rev().flat_map(|frame| frame.iter().rev()) {
+                    let _ = write!(line, ";{}", symbol);
+                }
+                let _ = write!(line, " {}", count);
+                line
+            })
+            .collect();
+        lines.sort_unstable();
+        Ok(lines)
+    }
+}
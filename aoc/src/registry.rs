@@ -0,0 +1,133 @@
+use std::io::BufRead;
+
+use aoc_common::{
+    solve_part1, solve_part1_observed, solve_part2, solve_part2_observed, solve_stream_part1, solve_stream_part2, PhaseObserver, Result,
+    Solution, Solved, StreamingSolution,
+};
+
+pub struct Solver {
+    pub day: u8,
+    pub part: u8,
+    pub solve: fn(&str) -> Result<Solved>,
+    /// Same as `solve`, but notifies the observer around the parsing and solving.
+    pub solve_observed: fn(&str, &mut dyn PhaseObserver) -> Result<Solved>,
+}
+
+const fn part1<S: Solution>() -> Solver {
+    Solver {
+        day: S::DAY,
+        part: 1,
+        solve: solve_part1::<S>,
+        solve_observed: solve_part1_observed::<S>,
+    }
+}
+
+const fn part2<S: Solution>() -> Solver {
+    Solver {
+        day: S::DAY,
+        part: 2,
+        solve: solve_part2::<S>,
+        solve_observed: solve_part2_observed::<S>,
+    }
+}
+
+pub static SOLVERS: [Solver; 20] = [
+    part1::<day01::Day01>(),
+    part2::<day01::Day01>(),
+    part1::<day02::Day02>(),
+    part2::<day02::Day02>(),
+    part1::<day03::Day03>(),
+    part2::<day03::Day03>(),
+    part1::<day04::Day04>(),
+    part2::<day04::Day04>(),
+    part1::<day05::Day05>(),
+    part2::<day05::Day05>(),
+    part1::<day06::Day06>(),
+    part2::<day06::Day06>(),
+    part1::<day07::Day07>(),
+    part2::<day07::Day07>(),
+    part1::<day08::Day08>(),
+    part2::<day08::Day08>(),
+    part1::<day09::Day09>(),
+    part2::<day09::Day09>(),
+    part1::<day10::Day10>(),
+    part2::<day10::Day10>(),
+];
+
+pub fn find_solvers(day: u8, part: Option<u8>) -> Vec<&'static Solver> {
+    SOLVERS
+        .iter()
+        .filter(|solver| solver.day == day && part.is_none_or(|part| solver.part == part))
+        .collect()
+}
+
+/// Solver of a day that can read its input as a stream, see [`StreamingSolution`].
+pub struct StreamSolver {
+    pub day: u8,
+    pub part: u8,
+    pub solve: fn(&mut dyn BufRead) -> Result<Solved>,
+}
+
+const fn stream_part1<S: StreamingSolution>() -> StreamSolver {
+    StreamSolver {
+        day: S::DAY,
+        part: 1,
+        solve: solve_stream_part1::<S>,
+    }
+}
+
+const fn stream_part2<S: StreamingSolution>() -> StreamSolver {
+    StreamSolver {
+        day: S::DAY,
+        part: 2,
+        solve: solve_stream_part2::<S>,
+    }
+}
+
+pub static STREAM_SOLVERS: [StreamSolver; 10] = [
+    stream_part1::<day01::Day01>(),
+    stream_part2::<day01::Day01>(),
+    stream_part1::<day02::Day02>(),
+    stream_part2::<day02::Day02>(),
+    stream_part1::<day04::Day04>(),
+    stream_part2::<day04::Day04>(),
+    stream_part1::<day07::Day07>(),
+    stream_part2::<day07::Day07>(),
+    stream_part1::<day09::Day09>(),
+    stream_part2::<day09::Day09>(),
+];
+
+pub fn find_stream_solvers(day: u8, part: Option<u8>) -> Vec<&'static StreamSolver> {
+    STREAM_SOLVERS
+        .iter()
+        .filter(|solver| solver.day == day && part.is_none_or(|part| solver.part == part))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solvers_are_unique() {
+        for solver in SOLVERS.iter() {
+            assert_eq!(find_solvers(solver.day, Some(solver.part)).len(), 1);
+        }
+    }
+
+    #[test]
+    fn test_find_solvers() {
+        assert_eq!(find_solvers(5, None).len(), 2);
+        assert_eq!(find_solvers(8, Some(2)).len(), 1);
+        assert!(find_solvers(26, None).is_empty());
+    }
+
+    #[test]
+    fn test_stream_solvers_have_solvers() {
+        for solver in STREAM_SOLVERS.iter() {
+            assert_eq!(find_solvers(solver.day, Some(solver.part)).len(), 1);
+            assert_eq!(find_stream_solvers(solver.day, Some(solver.part)).len(), 1);
+        }
+        assert!(find_stream_solvers(5, None).is_empty());
+    }
+}
@@ -0,0 +1,174 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use aoc_common::input::find_in_ancestors;
+
+const CARGO_TOML: &str = include_str!("templates/Cargo.toml.tmpl");
+const LIB: &str = include_str!("templates/lib.rs.tmpl");
+const BIN: &str = include_str!("templates/bin.rs.tmpl");
+const EXAMPLES: &str = include_str!("templates/examples.rs.tmpl");
+const BENCH: &str = include_str!("templates/bench.rs.tmpl");
+
+/// Source of the runner's registry, relative to the workspace root.
+const REGISTRY_PATH: &str = "aoc/src/registry.rs";
+
+/// Workspace holding the runner sources, searched from the current directory upwards.
+pub fn find_workspace_root() -> Result<PathBuf> {
+    let registry = find_in_ancestors(Path::new(REGISTRY_PATH))?;
+    registry
+        .ancestors()
+        .nth(3)
+        .map(Path::to_path_buf)
+        .with_context(|| format!("{} is not inside a workspace", registry.display()))
+}
+
+fn render(template: &str, day: u8, part: u8) -> String {
+    template
+        .replace("{{crate}}", &format!("day{:02}", day))
+        .replace("{{type}}", &format!("Day{:02}", day))
+        .replace("{{day}}", &day.to_string())
+        .replace("{{part}}", &part.to_string())
+}
+
+/// Day parsed from a line like `day05 = ...` or `part1::<day05::Day05>(),` starting with `prefix`.
+fn line_day(line: &str, prefix: &str) -> Option<u8> {
+    line.trim_start().strip_prefix(prefix)?.get(..2)?.parse().ok()
+}
+
+/// Inserts `new_lines` among the lines `day_of` finds a day in, which are sorted by their day.
+fn insert_day_lines(text: &str, day_of: impl Fn(&str) -> Option<u8>, day: u8, new_lines: &[String]) -> Result<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let entries: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, day_of(line)?)))
+        .collect();
+    if entries.iter().any(|(_, entry_day)| *entry_day == day) {
+        bail!("Day {} is registered already", day);
+    }
+    let position = match entries.iter().rev().find(|(_, entry_day)| *entry_day < day) {
+        Some((index, _)) => index + 1,
+        None => entries.first().map(|(index, _)| *index).context("No lines of other days found")?,
+    };
+
+    let first_entry = lines[entries[0].0];
+    let indent = &first_entry[..first_entry.len() - first_entry.trim_start().len()];
+    let mut output: Vec<String> = lines[..position].iter().map(|line| line.to_string()).collect();
+    output.extend(new_lines.iter().map(|line| format!("{}{}", indent, line)));
+    output.extend(lines[position..].iter().map(|line| line.to_string()));
+    Ok(output.join("\n") + "\n")
+}
+
+/// Adds the crate to the `members` and the dependencies of the workspace manifest.
+fn register_in_workspace(manifest: &str, day: u8) -> Result<String> {
+    let name = format!("day{:02}", day);
+    let mut lines: Vec<String> = manifest.lines().map(str::to_string).collect();
+    let members_line = lines
+        .iter_mut()
+        .find(|line| line.starts_with("members = ["))
+        .context("The workspace manifest has no single line `members` list")?;
+    let members = members_line.trim_start_matches("members = [").trim_end_matches(']');
+    let mut members: Vec<&str> = members.split(',').map(str::trim).filter(|member| !member.is_empty()).collect();
+    let quoted = format!("\"{}\"", name);
+    members.push(&quoted);
+    members.sort_unstable();
+    *members_line = format!("members = [{}]", members.join(", "));
+
+    insert_day_lines(&(lines.join("\n") + "\n"), |line| line_day(line, "day"), day, &[format!("{} = {{ path = \"{}\" }}", name, name)])
+}
+
+fn register_in_runner_manifest(manifest: &str, day: u8) -> Result<String> {
+    insert_day_lines(manifest, |line| line_day(line, "day"), day, &[format!("day{:02}.workspace = true", day)])
+}
+
+fn register_in_registry(registry: &str, day: u8) -> Result<String> {
+    let solution = format!("day{:02}::Day{:02}", day, day);
+    let solver_day = |line: &str| line_day(line, "part1::<day").or_else(|| line_day(line, "part2::<day"));
+    let registry = insert_day_lines(registry, solver_day, day, &[format!("part1::<{}>(),", solution), format!("part2::<{}>(),", solution)])?;
+
+    let count = registry.lines().filter(|line| solver_day(line).is_some()).count();
+    let (before, after) = registry.split_once("pub static SOLVERS: [Solver; ").context("The registry has no `SOLVERS` array")?;
+    let (_, after) = after.split_once(']').context("The registry has no `SOLVERS` array")?;
+    Ok(format!("{}pub static SOLVERS: [Solver; {}]{}", before, count, after))
+}
+
+fn update_file(path: &Path, update: impl FnOnce(&str) -> Result<String>) -> Result<()> {
+    let text = fs::read_to_string(path).with_context(|| format!("Could not read {}", path.display()))?;
+    let text = update(&text).with_context(|| format!("Could not update {}", path.display()))?;
+    fs::write(path, text).with_context(|| format!("Could not write {}", path.display()))
+}
+
+/// Creates the crate of `day` in the workspace at `root` and returns the created files.
+///
+/// Existing files in the day's `resources` are kept, so inputs can be fetched before the crate is created.
+pub fn create_day(root: &Path, day: u8) -> Result<Vec<PathBuf>> {
+    let name = format!("day{:02}", day);
+    let crate_dir = root.join(&name);
+    if crate_dir.join("Cargo.toml").exists() {
+        bail!("{} exists already", crate_dir.display());
+    }
+
+    let files = [
+        (crate_dir.join("Cargo.toml"), render(CARGO_TOML, day, 0)),
+        (crate_dir.join("src").join("lib.rs"), render(LIB, day, 0)),
+        (crate_dir.join("src").join("bin").join(format!("{}_part1.rs", name)), render(BIN, day, 1)),
+        (crate_dir.join("src").join("bin").join(format!("{}_part2.rs", name)), render(BIN, day, 2)),
+        (crate_dir.join("tests").join("examples.rs"), render(EXAMPLES, day, 0)),
+        (crate_dir.join("benches").join(format!("{}.rs", name)), render(BENCH, day, 0)),
+        (crate_dir.join("resources").join("input.txt"), String::new()),
+        (crate_dir.join("resources").join("test_input.txt"), String::new()),
+    ];
+
+    let mut created = Vec::new();
+    for (path, content) in files {
+        if path.exists() {
+            continue;
+        }
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).with_context(|| format!("Could not create {}", dir.display()))?;
+        }
+        fs::write(&path, content).with_context(|| format!("Could not write {}", path.display()))?;
+        created.push(path);
+    }
+
+    update_file(&root.join("Cargo.toml"), |text| register_in_workspace(text, day))?;
+    update_file(&root.join("aoc").join("Cargo.toml"), |text| register_in_runner_manifest(text, day))?;
+    update_file(&root.join(REGISTRY_PATH), |text| register_in_registry(text, day))?;
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_templates() {
+        for template in [CARGO_TOML, LIB, BIN, EXAMPLES, BENCH] {
+            let rendered = render(template, 11, 2);
+            assert!(!rendered.contains("{{"), "{}", rendered);
+        }
+        assert!(render(BIN, 11, 2).contains("solve_part2::<Day11>"));
+        assert!(render(LIB, 11, 0).contains("const DAY: u8 = 11;"));
+    }
+
+    #[test]
+    fn test_register_in_workspace() -> Result<()> {
+        let manifest = "[workspace]\nmembers = [\"aoc\", \"day01\", \"day03\"]\n\n[workspace.dependencies]\nanyhow = \"1\"\nday01 = { path = \"day01\" }\nday03 = { path = \"day03\" }\n";
+        let manifest = register_in_workspace(manifest, 2)?;
+        assert!(manifest.contains("members = [\"aoc\", \"day01\", \"day02\", \"day03\"]"));
+        assert!(manifest.contains("day01 = { path = \"day01\" }\nday02 = { path = \"day02\" }\nday03"));
+        assert!(register_in_workspace(&manifest, 2).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_register_in_registry() -> Result<()> {
+        let registry = "pub static SOLVERS: [Solver; 4] = [\n    part1::<day09::Day09>(),\n    part2::<day09::Day09>(),\n    part1::<day12::Day12>(),\n    part2::<day12::Day12>(),\n];\n";
+        let registry = register_in_registry(registry, 11)?;
+        assert!(registry.contains("pub static SOLVERS: [Solver; 6] = ["));
+        assert!(registry.contains("    part2::<day09::Day09>(),\n    part1::<day11::Day11>(),\n    part2::<day11::Day11>(),\n    part1::<day12::Day12>(),"));
+        assert!(register_in_registry(&registry, 11).is_err());
+        Ok(())
+    }
+}
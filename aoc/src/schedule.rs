@@ -0,0 +1,52 @@
+use anyhow::{Context, Result};
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+
+/// Runs `task` for all `items` concurrently on a new pool of `threads` threads, one per CPU if `None`.
+///
+/// The solutions parallelizing with rayon themselves run their work on the same pool, as rayon schedules nested
+/// parallel iterators on the pool of the task calling them, so the pool is never oversubscribed. The results are
+/// returned in the order of `items`, no matter in which order the tasks finished.
+pub fn run_parallel<T: Sync, R: Send>(items: &[T], threads: Option<usize>, task: impl Fn(&T) -> R + Sync) -> Result<Vec<R>> {
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(threads.unwrap_or(0))
+        .thread_name(|index| format!("aoc-worker-{}", index))
+        .build()
+        .context("Could not create the thread pool")?;
+    Ok(pool.install(|| items.par_iter().map(&task).collect()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_results_keep_the_order_of_the_items() -> Result<()> {
+        let items: Vec<u64> = (0..64).collect();
+        let results = run_parallel(&items, Some(4), |item| {
+            // Finish the later items first.
+            thread::sleep(Duration::from_micros(64 - item));
+            item * 2
+        })?;
+        assert_eq!(results, items.iter().map(|item| item * 2).collect::<Vec<u64>>());
+        Ok(())
+    }
+
+    #[test]
+    fn test_nested_parallelism_uses_the_pool() -> Result<()> {
+        let items = [1_u64, 2, 3];
+        let results = run_parallel(&items, Some(2), |item| {
+            assert_eq!(rayon::current_num_threads(), 2);
+            (0..1000_u64).into_par_iter().map(|value| value * item).sum::<u64>()
+        })?;
+        assert_eq!(results, [499500, 999000, 1498500]);
+
+        // A single thread has to run the nested iterators itself instead of waiting for other threads.
+        let results = run_parallel(&items, Some(1), |item| (0..10_u64).into_par_iter().map(|value| value + item).count())?;
+        assert_eq!(results, [10, 10, 10]);
+        Ok(())
+    }
+}
@@ -0,0 +1,312 @@
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::fetch::{unescape_html, Cache, Client};
+
+/// Name of the history of the submissions of a day in the day's cache directory.
+pub const HISTORY_FILE: &str = "submissions.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// The answer was submitted too soon after the previous one and was not checked.
+    Wait,
+    /// The part was solved already, so the answer was not checked.
+    AlreadySolved,
+}
+
+impl Verdict {
+    fn is_wrong(self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "right",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wait => "submitted too soon",
+            Verdict::AlreadySolved => "for a part solved already",
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Response {
+    pub verdict: Verdict,
+    /// Time to wait before the next answer may be submitted.
+    pub wait: Option<Duration>,
+    pub message: String,
+}
+
+fn parse_number_word(word: &str) -> Option<u64> {
+    match word {
+        "one" | "a" | "an" => Some(1),
+        word => word.parse().ok(),
+    }
+}
+
+/// Parses the waiting time of messages like `please wait 5 minutes before` or `You have 4m 32s left to wait`.
+fn parse_wait(message: &str) -> Option<Duration> {
+    if let Some((before, _)) = message.split_once(" left to wait") {
+        let (_, left) = before.rsplit_once("You have ")?;
+        let mut seconds = 0;
+        for token in left.split_whitespace() {
+            let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            seconds += match unit {
+                "h" => value * 3600,
+                "m" => value * 60,
+                "s" => value,
+                _ => return None,
+            };
+        }
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let message = message.to_lowercase();
+    let (_, rest) = message.split_once("please wait ")?;
+    let mut words = rest.split_whitespace();
+    let value = parse_number_word(words.next()?)?;
+    match words.next()?.trim_end_matches('s') {
+        "hour" => Some(Duration::from_secs(value * 3600)),
+        "minute" => Some(Duration::from_secs(value * 60)),
+        "second" => Some(Duration::from_secs(value)),
+        _ => None,
+    }
+}
+
+/// Interprets the page answering a submission by the text of its `<article>`.
+pub fn parse_response(page: &str) -> Result<Response> {
+    let article = page
+        .split_once("<article>")
+        .and_then(|(_, article)| article.split_once("</article>"))
+        .map_or(page, |(article, _)| article);
+    let message = unescape_html(article).split_whitespace().collect::<Vec<&str>>().join(" ");
+
+    let verdict = if message.contains("That's the right answer") {
+        Verdict::Correct
+    } else if message.contains("That's not the right answer") {
+        if message.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if message.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else if message.contains("You gave an answer too recently") {
+        Verdict::Wait
+    } else if message.contains("You don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else {
+        bail!("Unexpected response to the submission: {}", message);
+    };
+    Ok(Response { verdict, wait: parse_wait(&message), message })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch when the answer was submitted.
+    pub submitted_at: u64,
+    /// Seconds since the Unix epoch before which no other answer may be submitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_after: Option<u64>,
+}
+
+/// Answers submitted for one day, kept to reject answers that are known to be wrong without asking the server.
+pub struct History {
+    path: PathBuf,
+    pub submissions: Vec<Submission>,
+}
+
+pub fn unix_seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).map_or(0, |duration| duration.as_secs())
+}
+
+impl History {
+    pub fn load(path: &Path) -> Result<Self> {
+        let submissions = match fs::read_to_string(path) {
+            Ok(text) => serde_json::from_str(&text).with_context(|| format!("Invalid submission history {}", path.display()))?,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(error) => return Err(error).with_context(|| format!("Could not read {}", path.display())),
+        };
+        Ok(History { path: path.to_path_buf(), submissions })
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).with_context(|| format!("Could not create {}", dir.display()))?;
+        }
+        fs::write(&self.path, serde_json::to_string_pretty(&self.submissions)? + "\n")
+            .with_context(|| format!("Could not write {}", self.path.display()))
+    }
+
+    /// Fails if submitting `answer` at `now` is known to be pointless or would be rejected for submitting too soon.
+    pub fn check(&self, part: u8, answer: &str, now: u64) -> Result<()> {
+        let part_submissions = || self.submissions.iter().filter(move |submission| submission.part == part);
+        if let Some(correct) = part_submissions().find(|submission| submission.verdict == Verdict::Correct) {
+            bail!("Part {} was solved already with {}", part, correct.answer);
+        }
+        if let Some(wrong) = part_submissions().find(|submission| submission.answer == answer && submission.verdict.is_wrong()) {
+            bail!("{} was submitted already and was {}", answer, wrong.verdict);
+        }
+        if let Ok(value) = answer.parse::<i128>() {
+            for submission in part_submissions() {
+                let Ok(submitted) = submission.answer.parse::<i128>() else {
+                    continue;
+                };
+                match submission.verdict {
+                    Verdict::TooHigh if value >= submitted => bail!("{} is not lower than {}, which was too high", answer, submitted),
+                    Verdict::TooLow if value <= submitted => bail!("{} is not higher than {}, which was too low", answer, submitted),
+                    _ => {}
+                }
+            }
+        }
+        // The server limits the submissions of all parts together.
+        if let Some(retry_after) = self.submissions.iter().filter_map(|submission| submission.retry_after).max() {
+            if now < retry_after {
+                bail!("Submitting again is possible in {}s", retry_after - now);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Submits `answer` unless the history of the day rejects it, and records the verdict in the history.
+pub fn submit(client: &Client, cache: &Cache, day: u8, part: u8, answer: &str, now: SystemTime) -> Result<Response> {
+    let mut history = History::load(&cache.day_file(day, HISTORY_FILE))?;
+    let now = unix_seconds(now);
+    history.check(part, answer, now)?;
+
+    let response = parse_response(&client.submit(day, part, answer)?)?;
+    history.submissions.push(Submission {
+        part,
+        answer: answer.to_string(),
+        verdict: response.verdict,
+        submitted_at: now,
+        retry_after: response.wait.map(|wait| now + wait.as_secs()),
+    });
+    history.save()?;
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::fetch::mock::MockServer;
+
+    const TOO_HIGH: &str = "<main>\n<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2023/about\">about page</a>.  Please wait one minute before trying again. <a href=\"/2023/day/9\">[Return to Day 9]</a></p></article>\n</main>";
+    const CORRECT: &str = "<main>\n<article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to restoring snow operations. <a href=\"/2023/day/9#part2\">[Continue to Part Two]</a></p></article>\n</main>";
+    const TOO_RECENT: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 32s left to wait. <a href=\"/2023/day/9\">[Return to Day 9]</a></p></article>";
+
+    fn temp_cache(name: &str) -> Cache {
+        let dir = env::temp_dir().join(format!("aoc-submit-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        Cache::new(dir)
+    }
+
+    #[test]
+    fn test_parse_response() -> Result<()> {
+        let response = parse_response(TOO_HIGH)?;
+        assert_eq!((response.verdict, response.wait), (Verdict::TooHigh, Some(Duration::from_secs(60))));
+        assert!(response.message.starts_with("That's not the right answer; your answer is too high. If"));
+
+        assert_eq!(parse_response(CORRECT)?.verdict, Verdict::Correct);
+        assert_eq!(parse_response(CORRECT)?.wait, None);
+        let response = parse_response(TOO_RECENT)?;
+        assert_eq!((response.verdict, response.wait), (Verdict::Wait, Some(Duration::from_secs(272))));
+        let response = parse_response("<article><p>That's not the right answer.  Please wait 5 minutes before trying again.</p></article>")?;
+        assert_eq!((response.verdict, response.wait), (Verdict::Wrong, Some(Duration::from_secs(300))));
+        let response = parse_response("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>")?;
+        assert_eq!(response.verdict, Verdict::AlreadySolved);
+        assert!(parse_response("<article><p>Something else</p></article>").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_history_check() {
+        let submission = |part, answer: &str, verdict, retry_after| Submission {
+            part,
+            answer: answer.to_string(),
+            verdict,
+            submitted_at: 100,
+            retry_after,
+        };
+        let history = History {
+            path: PathBuf::new(),
+            submissions: vec![
+                submission(1, "500", Verdict::TooHigh, Some(160)),
+                submission(1, "100", Verdict::TooLow, None),
+                submission(1, "abc", Verdict::Wrong, None),
+                submission(2, "42", Verdict::Correct, None),
+            ],
+        };
+        assert!(history.check(1, "300", 200).is_ok());
+        assert!(history.check(1, "300", 120).is_err_and(|error| error.to_string().contains("40s")));
+        assert!(history.check(1, "500", 200).is_err());
+        assert!(history.check(1, "600", 200).is_err());
+        assert!(history.check(1, "99", 200).is_err());
+        assert!(history.check(1, "abc", 200).is_err());
+        assert!(history.check(1, "abd", 200).is_ok());
+        assert!(history.check(2, "43", 200).is_err_and(|error| error.to_string().contains("solved already with 42")));
+    }
+
+    #[test]
+    fn test_submit_records_history() -> Result<()> {
+        let cache = temp_cache("history");
+        let now = UNIX_EPOCH + Duration::from_secs(1_000);
+
+        let server = MockServer::start(vec![("/2023/day/9/answer", TOO_HIGH)]);
+        let client = Client::new(&server.url(), Some("secret".to_string()));
+        let response = submit(&client, &cache, 9, 1, "2000", now)?;
+        assert_eq!(response.verdict, Verdict::TooHigh);
+        assert_eq!(server.requests(), vec!["/2023/day/9/answer"]);
+        assert_eq!(server.bodies(), vec!["level=1&answer=2000"]);
+        assert_eq!(server.cookies(), vec!["session=secret"]);
+
+        // Rejected locally, without contacting the server.
+        assert!(submit(&client, &cache, 9, 1, "1999", now + Duration::from_secs(30)).is_err());
+        assert!(submit(&client, &cache, 9, 1, "2001", now + Duration::from_secs(90)).is_err());
+        assert_eq!(server.requests().len(), 1);
+
+        let server = MockServer::start(vec![("/2023/day/9/answer", CORRECT)]);
+        let client = Client::new(&server.url(), Some("secret".to_string()));
+        assert_eq!(submit(&client, &cache, 9, 1, "1999", now + Duration::from_secs(90))?.verdict, Verdict::Correct);
+
+        let history = History::load(&cache.day_file(9, HISTORY_FILE))?;
+        let verdicts: Vec<(&str, Verdict, Option<u64>)> = history
+            .submissions
+            .iter()
+            .map(|submission| (submission.answer.as_str(), submission.verdict, submission.retry_after))
+            .collect();
+        assert_eq!(verdicts, vec![("2000", Verdict::TooHigh, Some(1_060)), ("1999", Verdict::Correct, None)]);
+        assert!(submit(&client, &cache, 9, 1, "1999", now + Duration::from_secs(200)).is_err());
+        assert_eq!(server.requests().len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_submit_needs_session() {
+        let server = MockServer::start(vec![("/2023/day/9/answer", CORRECT)]);
+        let error = submit(&Client::new(&server.url(), None), &temp_cache("session"), 9, 1, "1", SystemTime::now()).unwrap_err();
+        assert!(error.to_string().contains("AOC_SESSION"));
+        assert!(server.requests().is_empty());
+    }
+}
@@ -0,0 +1,148 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use aoc_common::input::{default_input_path, read_file, resource_path, DEFAULT_INPUT_NAME};
+
+use crate::registry::Solver;
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Recorded answers of every day and part, keyed by the name of the input they belong to.
+///
+/// The answers file uses tables like `[day05.part2]` holding `<input name> = "<answer>"` entries,
+/// where the input name is a file in the day's resources without its `.txt` extension.
+pub struct Answers {
+    answers: BTreeMap<(u8, u8), BTreeMap<String, String>>,
+}
+
+fn parse_key(key: &str, prefix: &str) -> Result<u8> {
+    key.strip_prefix(prefix)
+        .and_then(|number| number.parse().ok())
+        .with_context(|| format!("Expected a key like `{}01`, found `{}`", prefix, key))
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self> {
+        let table: toml::Table = text.parse()?;
+        let mut answers = BTreeMap::new();
+        for (day_key, parts) in table.iter() {
+            let day = parse_key(day_key, "day")?;
+            let parts = parts.as_table().with_context(|| format!("`{}` has to be a table of parts", day_key))?;
+            for (part_key, inputs) in parts.iter() {
+                let part = parse_key(part_key, "part")?;
+                let inputs = inputs
+                    .as_table()
+                    .with_context(|| format!("`{}.{}` has to be a table of answers", day_key, part_key))?;
+                let mut part_answers = BTreeMap::new();
+                for (input_name, answer) in inputs.iter() {
+                    let answer = match answer {
+                        toml::Value::String(answer) => answer.clone(),
+                        toml::Value::Integer(answer) => answer.to_string(),
+                        _ => bail!("Answer `{}.{}.{}` has to be a string or an integer", day_key, part_key, input_name),
+                    };
+                    part_answers.insert(input_name.clone(), answer);
+                }
+                answers.insert((day, part), part_answers);
+            }
+        }
+        Ok(Answers { answers })
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = read_file(path)?;
+        Self::parse(&text).with_context(|| format!("Invalid answers file {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&BTreeMap<String, String>> {
+        self.answers.get(&(day, part)).filter(|inputs| !inputs.is_empty())
+    }
+}
+
+pub enum Status {
+    Pass,
+    Fail { actual: String },
+    Missing { reason: String },
+}
+
+pub struct Check {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub expected: Option<String>,
+    pub status: Status,
+}
+
+fn load_named_input(day: u8, name: &str) -> aoc_common::Result<String> {
+    let path = if name == DEFAULT_INPUT_NAME {
+        default_input_path(day)?
+    } else {
+        resource_path(day, name)?
+    };
+    read_file(&path)
+}
+
+fn check_input(solver: &Solver, name: &str, expected: &str) -> Status {
+    let input = match load_named_input(solver.day, name) {
+        Ok(input) => input,
+        Err(error) => return Status::Missing { reason: error.to_string() },
+    };
+    match (solver.solve)(&input) {
+        Ok(solved) if solved.answer == expected => Status::Pass,
+        Ok(solved) => Status::Fail { actual: solved.answer },
+        Err(error) => Status::Fail { actual: format!("error: {}", error) },
+    }
+}
+
+pub fn verify(solvers: &[&Solver], answers: &Answers) -> Vec<Check> {
+    let mut checks = Vec::new();
+    for solver in solvers {
+        let Some(inputs) = answers.get(solver.day, solver.part) else {
+            checks.push(Check {
+                day: solver.day,
+                part: solver.part,
+                input: DEFAULT_INPUT_NAME.to_string(),
+                expected: None,
+                status: Status::Missing { reason: "no answer recorded".to_string() },
+            });
+            continue;
+        };
+
+        for (name, expected) in inputs {
+            checks.push(Check {
+                day: solver.day,
+                part: solver.part,
+                input: name.clone(),
+                expected: Some(expected.clone()),
+                status: check_input(solver, name, expected),
+            });
+        }
+    }
+    checks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::find_solvers;
+
+    #[test]
+    fn test_parse_answers() -> Result<()> {
+        let answers = Answers::parse("[day05.part1]\ninput = \"35\"\n\n[day05.part2]\ntest_input01 = 46\n")?;
+        assert_eq!(answers.get(5, 1).and_then(|inputs| inputs.get("input")).map(String::as_str), Some("35"));
+        assert_eq!(answers.get(5, 2).and_then(|inputs| inputs.get("test_input01")).map(String::as_str), Some("46"));
+        assert!(answers.get(6, 1).is_none());
+        assert!(Answers::parse("[five.part1]\ninput = \"35\"\n").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_example_inputs() -> Result<()> {
+        let answers = Answers::parse("[day07.part1]\ntest_input = \"6440\"\n\n[day07.part2]\ntest_input = \"1\"\n")?;
+        let checks = verify(&find_solvers(7, None), &answers);
+        assert_eq!(checks.len(), 2);
+        assert!(matches!(checks[0].status, Status::Pass));
+        assert!(matches!(&checks[1].status, Status::Fail { actual } if actual == "5905"));
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::SystemTime;
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+
+/// Modification time and length of every file below the watched paths.
+pub type Snapshot = BTreeMap<PathBuf, (SystemTime, u64)>;
+
+fn collect_files(path: &Path, snapshot: &mut Snapshot) -> io::Result<()> {
+    let metadata = match fs::metadata(path) {
+        Ok(metadata) => metadata,
+        // Editors replace files by renaming, so a file can vanish between listing and reading it.
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(error) => return Err(error),
+    };
+    if metadata.is_dir() {
+        for entry in fs::read_dir(path)? {
+            collect_files(&entry?.path(), snapshot)?;
+        }
+    } else {
+        snapshot.insert(path.to_path_buf(), (metadata.modified()?, metadata.len()));
+    }
+    Ok(())
+}
+
+pub fn snapshot(paths: &[PathBuf]) -> Result<Snapshot> {
+    let mut snapshot = Snapshot::new();
+    for path in paths {
+        collect_files(path, &mut snapshot).with_context(|| format!("Could not scan {}", path.display()))?;
+    }
+    Ok(snapshot)
+}
+
+/// Files that were added, removed or modified between two snapshots.
+pub fn changed_files(previous: &Snapshot, current: &Snapshot) -> Vec<PathBuf> {
+    let modified = current
+        .iter()
+        .filter(|(path, state)| previous.get(*path) != Some(*state))
+        .map(|(path, _)| path.clone());
+    let removed = previous.keys().filter(|path| !current.contains_key(*path)).cloned();
+    let mut changed: Vec<PathBuf> = modified.chain(removed).collect();
+    changed.sort_unstable();
+    changed
+}
+
+/// Runs cargo in the workspace at `root` with the output of the command shown to the user.
+fn cargo(root: &Path, args: &[&str]) -> Result<bool> {
+    let status = Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()))
+        .args(args)
+        .current_dir(root)
+        .status()
+        .context("Could not start cargo")?;
+    Ok(status.success())
+}
+
+/// Runs the example tests in `tests/examples.rs` of `day` and returns whether they passed.
+pub fn run_examples(root: &Path, day: u8, profile: &str) -> Result<bool> {
+    let package = format!("day{:02}", day);
+    cargo(root, &["test", "--quiet", "--profile", profile, "-p", &package, "--test", "examples"])
+}
+
+#[derive(Deserialize)]
+struct RunOutput {
+    part: u8,
+    answer: String,
+}
+
+/// Answers of every part of `day` for `input` by the runner built from the current sources.
+pub fn run_input(root: &Path, day: u8, input: Option<&str>, profile: &str) -> Result<BTreeMap<u8, String>> {
+    let day = day.to_string();
+    let mut args = vec!["run", "--quiet", "--profile", profile, "-p", "aoc", "--", "run", "--day", &day, "--format", "json"];
+    if let Some(input) = input {
+        args.extend(["--input", input]);
+    }
+    let output = Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()))
+        .args(&args)
+        .current_dir(root)
+        .stderr(Stdio::inherit())
+        .output()
+        .context("Could not start cargo")?;
+    if !output.status.success() {
+        bail!("Running day {} failed with {}", day, output.status);
+    }
+    let results: Vec<RunOutput> = serde_json::from_slice(&output.stdout).context("Unexpected output of the runner")?;
+    Ok(results.into_iter().map(|result| (result.part, result.answer)).collect())
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AnswerChange {
+    New,
+    Unchanged,
+    Changed { previous: String },
+    Removed { previous: String },
+}
+
+/// Compares the answers of every part with the ones of the previous run.
+pub fn diff_answers(previous: &BTreeMap<u8, String>, current: &BTreeMap<u8, String>) -> Vec<(u8, Option<String>, AnswerChange)> {
+    let mut parts: Vec<u8> = previous.keys().chain(current.keys()).copied().collect();
+    parts.sort_unstable();
+    parts.dedup();
+    parts
+        .into_iter()
+        .map(|part| {
+            let change = match (previous.get(&part), current.get(&part)) {
+                (None, _) => AnswerChange::New,
+                (Some(previous), Some(answer)) if previous == answer => AnswerChange::Unchanged,
+                (Some(previous), Some(_)) => AnswerChange::Changed { previous: previous.clone() },
+                (Some(previous), None) => AnswerChange::Removed { previous: previous.clone() },
+            };
+            (part, current.get(&part).cloned(), change)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_changed_files() {
+        let time = SystemTime::UNIX_EPOCH;
+        let previous = Snapshot::from([
+            (PathBuf::from("src/lib.rs"), (time, 10)),
+            (PathBuf::from("src/main.rs"), (time, 20)),
+            (PathBuf::from("resources/input.txt"), (time, 30)),
+        ]);
+        let current = Snapshot::from([
+            (PathBuf::from("src/lib.rs"), (time + Duration::from_secs(1), 10)),
+            (PathBuf::from("resources/input.txt"), (time, 30)),
+            (PathBuf::from("resources/test_input.txt"), (time, 5)),
+        ]);
+        assert_eq!(
+            changed_files(&previous, &current),
+            ["resources/test_input.txt", "src/lib.rs", "src/main.rs"].map(PathBuf::from)
+        );
+        assert!(changed_files(&current, &current).is_empty());
+    }
+
+    #[test]
+    fn test_diff_answers() {
+        let previous = BTreeMap::from([(1, "35".to_string()), (2, "46".to_string())]);
+        let current = BTreeMap::from([(1, "35".to_string()), (2, "47".to_string())]);
+        assert_eq!(
+            diff_answers(&previous, &current),
+            [
+                (1, Some("35".to_string()), AnswerChange::Unchanged),
+                (2, Some("47".to_string()), AnswerChange::Changed { previous: "46".to_string() }),
+            ]
+        );
+        assert_eq!(diff_answers(&BTreeMap::new(), &current)[0].2, AnswerChange::New);
+        assert_eq!(
+            diff_answers(&current, &BTreeMap::new())[1],
+            (2, None, AnswerChange::Removed { previous: "47".to_string() })
+        );
+    }
+}
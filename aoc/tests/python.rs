@@ -0,0 +1,33 @@
+#![cfg(feature = "python")]
+
+use std::error::Error;
+use std::ffi::CString;
+use std::fs;
+use std::path::Path;
+
+use aoc_common::python::run_with_module;
+use pyo3::prelude::*;
+
+type ModuleInit = fn(&Bound<'_, PyModule>) -> PyResult<()>;
+
+/// Name, module initializer and example input of every day with Python bindings.
+const MODULES: [(&str, ModuleInit, &str); 5] = [
+    ("day05", day05::python::day05, "test_input01"),
+    ("day07", day07::python::day07, "test_input"),
+    ("day08", day08::python::day08, "test_input03"),
+    ("day09", day09::python::day09, "test_input"),
+    ("day10", day10::python::day10, "test_input04"),
+];
+
+/// Runs `tests/python/dayNN.py` against the module of every day, with the day's example bound to `example`.
+#[test]
+fn python_bindings() -> Result<(), Box<dyn Error>> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    for (name, init, example) in MODULES {
+        let example = root.join("..").join(name).join("resources").join(format!("{}.txt", example));
+        let script = fs::read_to_string(root.join("tests").join("python").join(format!("{}.py", name)))?;
+        let code = CString::new(format!("example = open(r'{}').read()\n{}", example.display(), script))?;
+        run_with_module(name, init, &code).map_err(|error| format!("{}: {}", name, error))?;
+    }
+    Ok(())
+}
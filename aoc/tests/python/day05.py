@@ -0,0 +1,10 @@
+almanac = day05.parse_part1(example)
+assert day05.find_lowest_location(almanac) == 35
+assert min(start for start, _ in day05.compress_almanac(day05.parse_part2(example))) == 46
+assert day05.find_lowest_location_compression(day05.parse_part2(example)) == 46
+assert day05.part2(example) == "46"
+try:
+    day05.parse_part1("oops")
+    raise AssertionError("invalid input was accepted")
+except ValueError as error:
+    assert "line 1" in str(error)
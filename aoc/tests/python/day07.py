@@ -0,0 +1,10 @@
+hands = day07.parse_input(example)
+assert str(hands[0]) == "32T3K 765"
+assert day07.calculate_result(hands) == 6440
+assert day07.calculate_result(day07.parse_input_with_jokers(example)) == 5905
+assert day07.part1(example) == "6440"
+try:
+    day07.parse_input("oops")
+    raise AssertionError("invalid input was accepted")
+except ValueError as error:
+    assert "line 1" in str(error)
@@ -0,0 +1,13 @@
+network = day08.parse_input(example)
+assert day08.get_moves_to_solve_ghost(network) == 6
+assert day08.part2(example) == "6"
+try:
+    day08.get_moves_to_solve(network)
+    raise AssertionError("the example has no node AAA")
+except RuntimeError as error:
+    assert "does not contain" in str(error)
+try:
+    day08.parse_input("oops")
+    raise AssertionError("invalid input was accepted")
+except ValueError as error:
+    assert "line 1" in str(error)
@@ -0,0 +1,10 @@
+sequences = day09.parse_input(example)
+assert sequences[0] == [0, 3, 6, 9, 12, 15]
+assert day09.get_result_path1(sequences) == 114
+assert day09.get_result_path2(sequences) == 2
+assert day09.get_result_path1([[1, 2, 3]]) == 4
+try:
+    day09.parse_input("oops")
+    raise AssertionError("invalid input was accepted")
+except ValueError as error:
+    assert "line 1" in str(error)
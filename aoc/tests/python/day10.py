@@ -0,0 +1,8 @@
+tiles = day10.parse_input(example)
+assert day10.get_encased_cells_count(tiles) == 4
+assert day10.part2(example) == "4"
+try:
+    day10.parse_input("oops")
+    raise AssertionError("invalid input was accepted")
+except ValueError as error:
+    assert "line 1" in str(error)
@@ -0,0 +1,97 @@
+use std::hint::black_box;
+use std::str::FromStr;
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use day01::{calculate_number, calculate_number_digits_only, MissingDigits, Report, Tokenizer};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+/// Generator of `aoc gen --day 1`, shared with the bench as the runner is not a library.
+#[path = "../../aoc/src/generate/day01.rs"]
+mod generate;
+
+type Rng = ChaCha8Rng;
+
+const INPUT: &str = include_str!("../resources/input.txt");
+
+/// Size of the generated input the approaches are compared on as well.
+const LARGE_INPUT_BYTES: usize = 100 * 1024 * 1024;
+const LARGE_INPUT_SEED: u64 = 42;
+
+fn large_input() -> String {
+    let mut rng = Rng::seed_from_u64(LARGE_INPUT_SEED);
+    let mut input = String::with_capacity(LARGE_INPUT_BYTES);
+    while input.len() < LARGE_INPUT_BYTES {
+        input += &generate::generate(&mut rng, 1000).expect("Generating day 1 input can not fail");
+    }
+    input
+}
+
+/// Value of a line as computed originally, by formatting its first and last digit and parsing them again.
+fn replace_line_value(line: &str) -> u64 {
+    let mut digits = line.chars().filter(|x| x.is_ascii_digit());
+    let first_numeric_char = digits.next().unwrap();
+    let last_numeric_char = match digits.nth_back(0) {
+        Some(digit) => digit,
+        None => first_numeric_char,
+    };
+    u64::from_str(format!("{}{}", first_numeric_char, last_numeric_char).as_str()).unwrap()
+}
+
+/// Original part 1, copying every line into a `String`.
+fn replace_part1(input: &str) -> u64 {
+    let input_lines: Vec<String> = input.lines().map(|x| x.to_string()).collect();
+    input_lines.iter().map(|x| replace_line_value(x)).sum()
+}
+
+/// Original part 2, replacing the spelled out digits of every line one word after the other.
+fn replace_part2(input: &str) -> u64 {
+    let input_lines: Vec<String> = input
+        .lines()
+        .map(|x| x.to_string())
+        .map(|x| x.replace("one", "o1e"))
+        .map(|x| x.replace("two", "t2o"))
+        .map(|x| x.replace("three", "t3e"))
+        .map(|x| x.replace("four", "f4r"))
+        .map(|x| x.replace("five", "f5e"))
+        .map(|x| x.replace("six", "s6x"))
+        .map(|x| x.replace("seven", "s7n"))
+        .map(|x| x.replace("eight", "e8t"))
+        .map(|x| x.replace("nine", "n9e"))
+        .collect();
+    input_lines.iter().map(|x| replace_line_value(x)).sum()
+}
+
+fn bench_approaches(c: &mut Criterion, name: &str, input: &str) {
+    let digits = Tokenizer::digits();
+    let words = Tokenizer::digits_and_words();
+    let mut group = c.benchmark_group(name);
+    group.throughput(Throughput::Bytes(input.len() as u64));
+    group.bench_function("part1", |b| b.iter(|| calculate_number_digits_only(black_box(input))));
+    group.bench_function("part2", |b| b.iter(|| calculate_number(black_box(input))));
+    group.bench_function("part1_replace", |b| b.iter(|| replace_part1(black_box(input))));
+    group.bench_function("part2_replace", |b| b.iter(|| replace_part2(black_box(input))));
+    group.bench_function("part1_tokenizer", |b| {
+        b.iter(|| Report::collect(black_box(input), &digits, MissingDigits::Fail))
+    });
+    group.bench_function("part2_tokenizer", |b| {
+        b.iter(|| Report::collect(black_box(input), &words, MissingDigits::Fail))
+    });
+    group.finish();
+}
+
+fn bench_solve(c: &mut Criterion) {
+    bench_approaches(c, "day01/solve", INPUT);
+}
+
+fn bench_large(c: &mut Criterion) {
+    bench_approaches(c, "day01/large", &large_input());
+}
+
+criterion_group!(benches, bench_solve);
+criterion_group! {
+    name = large;
+    config = Criterion::default().sample_size(10);
+    targets = bench_large
+}
+criterion_main!(benches, large);
@@ -1,23 +1,33 @@
-use std::include_str;
-use std::str::FromStr;
+use std::io::{self, IsTerminal};
 
-fn main() {
-    let input = include_str!("../../resources/input01.txt");
-    let input_lines: Vec<String> = input.lines().map(|x| x.to_string()).collect();
+use aoc_common::input::load_input;
+use aoc_common::Solution;
+use anyhow::Result;
+use clap::Parser;
+use day01::{explain, Day01, MissingDigits, Report, Tokenizer};
 
-    let mut line_values = Vec::<u64>::new();
+#[derive(Parser)]
+struct Args {
+    /// Input file, `-` reads from stdin, defaults to the day's input
+    input: Option<String>,
+    /// What to do with lines without a digit: skip, zero or fail
+    #[arg(long, default_value = "fail")]
+    missing_digits: MissingDigits,
+    /// Print every line with its first and last number and the value they form
+    #[arg(long)]
+    explain: bool,
+}
 
-    for input_line in input_lines {
-        let mut digits = input_line.chars().filter(|x| x.is_ascii_digit());
-        let first_numeric_char = digits.next().unwrap();
-        let last_numeric_char = match digits.nth_back(0) {
-            Some(digit) => digit,
-            None => first_numeric_char,
-        };
-        line_values.push(
-            u64::from_str(format!("{}{}", first_numeric_char, last_numeric_char).as_str()).unwrap(),
-        );
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let input = load_input(Day01::DAY, args.input.as_deref())?;
+    let tokenizer = Tokenizer::digits();
+    if args.explain {
+        for explanation in explain(&input, &tokenizer, io::stdout().is_terminal()) {
+            println!("{}", explanation);
+        }
     }
-
-    println!("Sum: {}", line_values.iter().sum::<u64>());
+    let report = Report::collect(&input, &tokenizer, args.missing_digits)?;
+    println!("{}", report);
+    Ok(())
 }
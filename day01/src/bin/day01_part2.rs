@@ -1,54 +1,52 @@
-use std::include_str;
-use std::str::FromStr;
-
-fn calculate_number(input: &str) -> u64 {
-    let input_lines: Vec<String> = input
-        .lines()
-        .map(|x| x.to_string())
-        //.map(remove_text_digits)
-        .map(|x| x.replace("one", "o1e"))
-        .map(|x| x.replace("two", "t2o"))
-        .map(|x| x.replace("three", "t3e"))
-        .map(|x| x.replace("four", "f4r"))
-        .map(|x| x.replace("five", "f5e"))
-        .map(|x| x.replace("six", "s6x"))
-        .map(|x| x.replace("seven", "s7n"))
-        .map(|x| x.replace("eight", "e8t"))
-        .map(|x| x.replace("nine", "n9e"))
-        .collect();
-
-    let mut line_values = Vec::<u64>::new();
-
-    for input_line in input_lines {
-        let mut digits = input_line.chars().filter(|x| x.is_ascii_digit());
-        let first_numeric_char = digits.next().unwrap();
-        let last_numeric_char = match digits.nth_back(0) {
-            Some(digit) => digit,
-            None => first_numeric_char,
-        };
-        line_values.push(
-            u64::from_str(format!("{}{}", first_numeric_char, last_numeric_char).as_str()).unwrap(),
-        );
-    }
-
-    line_values.iter().sum::<u64>()
+use std::io::{self, IsTerminal};
+
+use aoc_common::input::load_input;
+use aoc_common::Solution;
+use anyhow::Result;
+use clap::Parser;
+use day01::{explain, Day01, MissingDigits, Report, Tokenizer, Vocabulary};
+
+#[derive(Parser)]
+struct Args {
+    /// Input file, `-` reads from stdin, defaults to the day's input
+    input: Option<String>,
+    /// Spelled out numbers to recognize: english, german, french, roman or the path of a list of `<word> <number>` lines
+    #[arg(long, default_value = "english")]
+    vocabulary: String,
+    /// What to do with lines without a digit or spelled out number: skip, zero or fail
+    #[arg(long, default_value = "fail")]
+    missing_digits: MissingDigits,
+    /// Print every line with its first and last number and the value they form
+    #[arg(long)]
+    explain: bool,
 }
 
-fn main() {
-    let input = include_str!("../../resources/input01.txt");
-    let value = calculate_number(input);
-    println!("Sum: {}", value);
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let vocabulary = Vocabulary::builtin_or_load(&args.vocabulary)?;
+    let input = load_input(Day01::DAY, args.input.as_deref())?;
+    let tokenizer = Tokenizer::new(&vocabulary);
+    if args.explain {
+        for explanation in explain(&input, &tokenizer, io::stdout().is_terminal()) {
+            println!("{}", explanation);
+        }
+    }
+    let report = Report::collect(&input, &tokenizer, args.missing_digits)?;
+    println!("{}", report);
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use day01::calculate_number;
 
     #[test]
-    fn test_function() {
+    fn test_function() -> Result<()> {
         let input = include_str!("../../resources/test_input01.txt");
-        let value = calculate_number(input);
+        let value = calculate_number(input)?;
 
         assert_eq!(value, 299);
+        Ok(())
     }
 }
@@ -0,0 +1,116 @@
+use std::fmt::{Display, Formatter};
+
+use crate::tokenizer::{Calibration, Token, TokenKind, Tokenizer};
+
+const FIRST_COLOR: &str = "\x1b[1;32m";
+const LAST_COLOR: &str = "\x1b[1;34m";
+/// Color of the bytes belonging to both the first and the last token, like the `t` of `eightwo`.
+const BOTH_COLOR: &str = "\x1b[1;36m";
+const RESET: &str = "\x1b[0m";
+
+/// How a line was decoded, displayed as the line with its first and last token and the value they form.
+pub struct Explanation<'a> {
+    /// Line number, counted from 1.
+    pub line: usize,
+    pub text: &'a str,
+    pub calibration: Option<Calibration>,
+    /// Highlights the tokens in the line with terminal colors.
+    pub color: bool,
+}
+
+fn contains(token: &Token, offset: usize) -> bool {
+    (token.offset..token.offset + token.len).contains(&offset)
+}
+
+impl Explanation<'_> {
+    fn write_highlighted(&self, f: &mut Formatter<'_>, calibration: &Calibration) -> std::fmt::Result {
+        let mut start = 0;
+        let mut color = None;
+        for (offset, _) in self.text.char_indices().chain([(self.text.len(), ' ')]) {
+            let next = match (contains(&calibration.first, offset), contains(&calibration.last, offset)) {
+                (true, true) => Some(BOTH_COLOR),
+                (true, false) => Some(FIRST_COLOR),
+                (false, true) => Some(LAST_COLOR),
+                (false, false) => None,
+            };
+            if next != color || offset == self.text.len() {
+                write!(f, "{}", &self.text[start..offset])?;
+                if color.is_some() {
+                    write!(f, "{}", RESET)?;
+                }
+                if let Some(next) = next {
+                    write!(f, "{}", next)?;
+                }
+                start = offset;
+                color = next;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_token(&self, f: &mut Formatter<'_>, name: &str, token: &Token) -> std::fmt::Result {
+        let kind = match token.kind {
+            TokenKind::Digit => "digit",
+            TokenKind::Word => "word",
+        };
+        let text = &self.text[token.offset..token.offset + token.len];
+        write!(f, "\n      {:<6}{} `{}` at byte {} = {}", name, kind, text, token.offset, token.number)
+    }
+}
+
+impl Display for Explanation<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:>4}  ", self.line)?;
+        let Some(calibration) = self.calibration else {
+            return write!(f, "{}  ->  no digit", self.text);
+        };
+        if self.color {
+            self.write_highlighted(f, &calibration)?;
+        } else {
+            write!(f, "{}", self.text)?;
+        }
+        write!(f, "  ->  {}", calibration.value)?;
+        self.write_token(f, "first", &calibration.first)?;
+        self.write_token(f, "last", &calibration.last)
+    }
+}
+
+/// Explanations of how `tokenizer` decodes every line of `input`.
+pub fn explain<'a>(input: &'a str, tokenizer: &'a Tokenizer, color: bool) -> impl Iterator<Item = Explanation<'a>> + 'a {
+    input.lines().enumerate().map(move |(line_index, text)| Explanation {
+        line: line_index + 1,
+        text,
+        calibration: tokenizer.decode_line(text),
+        color,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn explanations(input: &str, color: bool) -> Vec<String> {
+        explain(input, &Tokenizer::digits_and_words(), color).map(|explanation| explanation.to_string()).collect()
+    }
+
+    #[test]
+    fn test_plain_explanation() {
+        let lines = explanations("xtwone3\nnothing\n", false);
+        assert_eq!(
+            lines,
+            [
+                "   1  xtwone3  ->  23\n      first word `two` at byte 1 = 2\n      last  digit `3` at byte 6 = 3",
+                "   2  nothing  ->  no digit",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_highlighted_tokens() {
+        let lines = explanations("eightwo\nä7b", true);
+        let highlighted = format!("{}eigh{}{}t{}{}wo{}", FIRST_COLOR, RESET, BOTH_COLOR, RESET, LAST_COLOR, RESET);
+        assert!(lines[0].starts_with(&format!("   1  {}  ->  82", highlighted)), "{}", lines[0]);
+        assert!(lines[1].starts_with(&format!("   2  ä{}7{}b  ->  77", BOTH_COLOR, RESET)), "{}", lines[1]);
+        assert!(lines[1].ends_with("last  digit `7` at byte 2 = 7"), "{}", lines[1]);
+    }
+}
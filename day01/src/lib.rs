@@ -0,0 +1,82 @@
+use std::io::BufRead;
+
+use aoc_common::{Result, Solution, StreamingSolution};
+
+pub mod explain;
+mod matcher;
+pub mod report;
+pub mod scan;
+pub mod tokenizer;
+pub mod vocabulary;
+
+pub use explain::{explain, Explanation};
+pub use report::{MissingDigits, MissingLine, Report};
+pub use tokenizer::{Calibration, Token, TokenKind, Tokenizer};
+pub use vocabulary::Vocabulary;
+
+fn sum_values(input: &str, tokenizer: &Tokenizer) -> Result<u64> {
+    Ok(Report::collect(input, tokenizer, MissingDigits::Fail)?.sum)
+}
+
+pub fn calculate_number_digits_only(input: &str) -> Result<u64> {
+    scan::sum_digits(input.as_bytes())
+}
+
+pub fn calculate_number(input: &str) -> Result<u64> {
+    scan::sum_digits_and_words(input.as_bytes())
+}
+
+/// Sum of the calibration values with the numbers of `vocabulary` recognized besides the digits.
+pub fn calculate_number_with(input: &str, vocabulary: &Vocabulary) -> Result<u64> {
+    sum_values(input, &Tokenizer::new(vocabulary))
+}
+
+fn sum_values_stream<R: BufRead>(reader: R, tokenizer: &Tokenizer) -> Result<u64> {
+    Ok(Report::collect_stream(reader, tokenizer, MissingDigits::Fail)?.sum)
+}
+
+pub fn calculate_number_digits_only_stream<R: BufRead>(reader: R) -> Result<u64> {
+    scan::sum_digits_stream(reader)
+}
+
+pub fn calculate_number_stream<R: BufRead>(reader: R) -> Result<u64> {
+    scan::sum_digits_and_words_stream(reader)
+}
+
+pub fn calculate_number_with_stream<R: BufRead>(reader: R, vocabulary: &Vocabulary) -> Result<u64> {
+    sum_values_stream(reader, &Tokenizer::new(vocabulary))
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input<'a> = &'a str;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1> {
+        calculate_number_digits_only(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2> {
+        calculate_number(input)
+    }
+}
+
+impl StreamingSolution for Day01 {
+    fn part1_stream(reader: &mut dyn BufRead) -> Result<Self::Answer1> {
+        calculate_number_digits_only_stream(reader)
+    }
+
+    fn part2_stream(reader: &mut dyn BufRead) -> Result<Self::Answer2> {
+        calculate_number_stream(reader)
+    }
+}
+
+#[cfg(feature = "wasm")]
+aoc_common::wasm_exports!(Day01);
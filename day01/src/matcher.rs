@@ -0,0 +1,154 @@
+use std::collections::VecDeque;
+
+/// Aho-Corasick automaton over the bytes of a set of words, with the transitions of every state resolved in advance.
+struct Automaton {
+    transitions: Vec<[u32; 256]>,
+    /// Length and index of the longest word ending in each state.
+    longest: Vec<Option<(usize, usize)>>,
+    max_len: usize,
+}
+
+impl Automaton {
+    fn new<'a>(words: impl Iterator<Item = impl Iterator<Item = &'a u8>>) -> Self {
+        let mut transitions = vec![[0_u32; 256]];
+        let mut longest = vec![None];
+        let mut max_len = 0;
+        for (index, word) in words.enumerate() {
+            let mut state = 0;
+            let mut len = 0;
+            for &byte in word {
+                if transitions[state][usize::from(byte)] == 0 {
+                    transitions[state][usize::from(byte)] = transitions.len() as u32;
+                    transitions.push([0; 256]);
+                    longest.push(None);
+                }
+                state = transitions[state][usize::from(byte)] as usize;
+                len += 1;
+            }
+            if len > 0 {
+                longest[state] = Some((len, index));
+                max_len = max_len.max(len);
+            }
+        }
+
+        // Breadth first, so the fallback of every state is complete before the states below it.
+        let mut fallbacks = vec![0_usize; transitions.len()];
+        let mut queue: VecDeque<usize> = transitions[0].iter().filter(|&&next| next != 0).map(|&next| next as usize).collect();
+        while let Some(state) = queue.pop_front() {
+            let fallback = fallbacks[state];
+            if longest[state].is_none() {
+                longest[state] = longest[fallback];
+            }
+            let fallback_transitions = transitions[fallback];
+            for (next, fallback_next) in transitions[state].iter_mut().zip(fallback_transitions) {
+                if *next == 0 {
+                    *next = fallback_next;
+                } else {
+                    fallbacks[*next as usize] = fallback_next as usize;
+                    queue.push_back(*next as usize);
+                }
+            }
+        }
+        Automaton { transitions, longest, max_len }
+    }
+
+    /// Word starting first in `bytes`, the longest one of the words starting there, as `(start, len, index)`.
+    fn find(&self, bytes: impl Iterator<Item = u8>) -> Option<(usize, usize, usize)> {
+        let mut state = 0;
+        let mut best: Option<(usize, usize, usize)> = None;
+        for (position, byte) in bytes.enumerate() {
+            // No word starting before the best one can end here or later.
+            if best.is_some_and(|(start, _, _)| position >= start + self.max_len) {
+                break;
+            }
+            state = self.transitions[state][usize::from(byte)] as usize;
+            if let Some((len, index)) = self.longest[state] {
+                let start = position + 1 - len;
+                if best.is_none_or(|(best_start, best_len, _)| start < best_start || (start == best_start && len > best_len)) {
+                    best = Some((start, len, index));
+                }
+            }
+        }
+        best
+    }
+}
+
+/// Finds the first and the last of a set of words in a text, scanning it once from the start or from the end.
+///
+/// Overlapping words are found from both sides, like `eight` and `two` in `eightwo`. Of words starting at the same
+/// byte the longest one is found first, and of words ending at the same byte the longest one is found last.
+pub struct Matcher {
+    forward: Automaton,
+    backward: Automaton,
+}
+
+/// Word found by a [`Matcher`], `offset` and `len` are counted in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    pub index: usize,
+    pub offset: usize,
+    pub len: usize,
+}
+
+impl Matcher {
+    pub fn new<W: AsRef<[u8]>>(words: &[W]) -> Self {
+        Matcher {
+            forward: Automaton::new(words.iter().map(|word| word.as_ref().iter())),
+            backward: Automaton::new(words.iter().map(|word| word.as_ref().iter().rev())),
+        }
+    }
+
+    pub fn find_first(&self, text: &[u8]) -> Option<Match> {
+        let (start, len, index) = self.forward.find(text.iter().copied())?;
+        Some(Match { index, offset: start, len })
+    }
+
+    pub fn find_last(&self, text: &[u8]) -> Option<Match> {
+        let (start, len, index) = self.backward.find(text.iter().rev().copied())?;
+        Some(Match { index, offset: text.len() - start - len, len })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overlapping_words() {
+        let matcher = Matcher::new(&["eight", "two", "one"]);
+        let text = b"xtwoneightwo";
+        assert_eq!(matcher.find_first(text), Some(Match { index: 1, offset: 1, len: 3 }));
+        assert_eq!(matcher.find_last(text), Some(Match { index: 1, offset: 9, len: 3 }));
+        assert_eq!(matcher.find_first(b"eightwo"), Some(Match { index: 0, offset: 0, len: 5 }));
+        assert_eq!(matcher.find_last(b"eightwo"), Some(Match { index: 1, offset: 4, len: 3 }));
+        assert_eq!(matcher.find_first(b"nothing"), None);
+    }
+
+    #[test]
+    fn test_nested_words() {
+        let matcher = Matcher::new(&["I", "II", "III", "V", "VIII", "bc", "abcd"]);
+        assert_eq!(matcher.find_first(b"xVIIIx"), Some(Match { index: 4, offset: 1, len: 4 }));
+        assert_eq!(matcher.find_last(b"xVIIIx"), Some(Match { index: 4, offset: 1, len: 4 }));
+        assert_eq!(matcher.find_last(b"VIII"), Some(Match { index: 4, offset: 0, len: 4 }));
+        // The word starting first wins, even if another word is complete earlier.
+        assert_eq!(matcher.find_first(b"abcd"), Some(Match { index: 6, offset: 0, len: 4 }));
+        assert_eq!(matcher.find_last(b"abcdbc"), Some(Match { index: 5, offset: 4, len: 2 }));
+    }
+
+    #[test]
+    fn test_matches_naive_search() {
+        let words = ["one", "two", "three", "eight", "nine", "ein", "eins", "neun", "1", "9"];
+        let matcher = Matcher::new(&words);
+        for text in ["oneight", "zweineunins", "xx1eeinsx9twone", "threeeightwothree", "nnineinn", ""] {
+            let matches: Vec<Match> = (0..text.len())
+                .flat_map(|offset| words.iter().enumerate().map(move |(index, word)| (offset, index, word)))
+                .filter(|(offset, _, word)| text[*offset..].starts_with(*word))
+                .map(|(offset, index, word)| Match { index, offset, len: word.len() })
+                .collect();
+            let first = matches.iter().min_by_key(|found| (found.offset, usize::MAX - found.len)).copied();
+            let last = matches.iter().max_by_key(|found| (found.offset + found.len, found.len)).copied();
+            assert_eq!(matcher.find_first(text.as_bytes()), first, "{}", text);
+            assert_eq!(matcher.find_last(text.as_bytes()), last, "{}", text);
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::str::FromStr;
+
+use aoc_common::stream::for_each_line;
+use aoc_common::{PuzzleError, Result};
+
+use crate::tokenizer::Tokenizer;
+
+/// What to do with a line that does not contain a number.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MissingDigits {
+    /// Leave the line out of the sum.
+    Skip,
+    /// Count the line with a calibration value of zero.
+    Zero,
+    /// Fail with the number of the line.
+    #[default]
+    Fail,
+}
+
+impl FromStr for MissingDigits {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "skip" => Ok(MissingDigits::Skip),
+            "zero" => Ok(MissingDigits::Zero),
+            "fail" => Ok(MissingDigits::Fail),
+            _ => Err(format!("Unknown policy `{}`, expected skip, zero or fail", s)),
+        }
+    }
+}
+
+/// Line without a number, `line` is counted from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingLine {
+    pub line: usize,
+    pub text: String,
+}
+
+/// Sum of the calibration values and the lines that did not contribute one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub sum: u64,
+    pub policy: MissingDigits,
+    pub missing: Vec<MissingLine>,
+}
+
+impl Report {
+    fn new(policy: MissingDigits) -> Self {
+        Report { sum: 0, policy, missing: Vec::new() }
+    }
+
+    /// Adds a calibration value, which is made of two numbers of up to nine digits and can overflow the sum.
+    fn add_value(&mut self, value: u64) -> Result<()> {
+        self.sum = self
+            .sum
+            .checked_add(value)
+            .ok_or(PuzzleError::LogicError { msg: "Sum of the calibration values does not fit into 64 bits!" })?;
+        Ok(())
+    }
+
+    fn add_line(&mut self, tokenizer: &Tokenizer, line_index: usize, line: &str) -> Result<()> {
+        if self.policy == MissingDigits::Fail {
+            return self.add_value(tokenizer.decode_numbered_line(line_index, line)?.value);
+        }
+        match tokenizer.decode_line(line) {
+            Some(calibration) => self.add_value(calibration.value)?,
+            None => self.missing.push(MissingLine { line: line_index + 1, text: line.to_string() }),
+        }
+        Ok(())
+    }
+
+    pub fn collect(input: &str, tokenizer: &Tokenizer, policy: MissingDigits) -> Result<Self> {
+        let mut report = Report::new(policy);
+        for (line_index, line) in input.lines().enumerate() {
+            report.add_line(tokenizer, line_index, line)?;
+        }
+        Ok(report)
+    }
+
+    pub fn collect_stream<R: BufRead>(reader: R, tokenizer: &Tokenizer, policy: MissingDigits) -> Result<Self> {
+        let mut report = Report::new(policy);
+        for_each_line(reader, |line_index, line| report.add_line(tokenizer, line_index, line))?;
+        Ok(report)
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Sum: {}", self.sum)?;
+        if !self.missing.is_empty() {
+            let action = match self.policy {
+                MissingDigits::Zero => "Counted as zero",
+                _ => "Skipped",
+            };
+            write!(f, "\n{} {} lines without a digit:", action, self.missing.len())?;
+            for missing in self.missing.iter() {
+                write!(f, "\n  line {}: `{}`", missing.line, missing.text)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vocabulary::{Vocabulary, MAX_NUMBER};
+
+    const INPUT: &str = "1abc2\nnothing\n\nseven8\n";
+
+    #[test]
+    fn test_policies() -> Result<()> {
+        let tokenizer = Tokenizer::digits();
+        let missing = vec![
+            MissingLine { line: 2, text: "nothing".to_string() },
+            MissingLine { line: 3, text: String::new() },
+        ];
+        for policy in [MissingDigits::Skip, MissingDigits::Zero] {
+            let report = Report::collect(INPUT, &tokenizer, policy)?;
+            assert_eq!(report, Report { sum: 100, policy, missing: missing.clone() });
+            assert_eq!(Report::collect_stream(INPUT.as_bytes(), &tokenizer, policy)?, report);
+        }
+        let error = Report::collect(INPUT, &tokenizer, MissingDigits::Fail).unwrap_err();
+        assert!(matches!(error, PuzzleError::ParserError { line: 2, .. }));
+        Ok(())
+    }
+
+    #[test]
+    fn test_display() -> Result<()> {
+        let report = Report::collect(INPUT, &Tokenizer::digits_and_words(), MissingDigits::Zero)?;
+        assert_eq!(report.to_string(), "Sum: 90\nCounted as zero 2 lines without a digit:\n  line 2: `nothing`\n  line 3: ``");
+        assert_eq!(Report::collect("12\n", &Tokenizer::digits(), MissingDigits::Skip)?.to_string(), "Sum: 12");
+        Ok(())
+    }
+
+    #[test]
+    fn test_sum_overflow() -> Result<()> {
+        let tokenizer = Tokenizer::new(&Vocabulary::new(vec![("big".to_string(), MAX_NUMBER)])?);
+        let line = "big big\n";
+        for policy in [MissingDigits::Skip, MissingDigits::Zero, MissingDigits::Fail] {
+            assert_eq!(Report::collect(line, &tokenizer, policy)?.sum, 999_999_999_999_999_999);
+            let error = Report::collect(&line.repeat(19), &tokenizer, policy).unwrap_err();
+            assert!(matches!(error, PuzzleError::LogicError { .. }));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_policy() {
+        assert_eq!("zero".parse(), Ok(MissingDigits::Zero));
+        assert!("ignore".parse::<MissingDigits>().is_err());
+    }
+}
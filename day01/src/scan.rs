@@ -0,0 +1,148 @@
+use std::io::BufRead;
+
+use aoc_common::stream::for_each_line;
+use aoc_common::{PuzzleError, Result};
+use memchr::memchr;
+
+const DIGIT_WORDS: [&[u8]; 9] = [b"one", b"two", b"three", b"four", b"five", b"six", b"seven", b"eight", b"nine"];
+
+/// Spelled out digits starting with each byte, as indices into [`DIGIT_WORDS`].
+fn words_starting_with(byte: u8) -> &'static [usize] {
+    match byte {
+        b'o' => &[0],
+        b't' => &[1, 2],
+        b'f' => &[3, 4],
+        b's' => &[5, 6],
+        b'e' => &[7],
+        b'n' => &[8],
+        _ => &[],
+    }
+}
+
+/// Spelled out digits ending with each byte, as indices into [`DIGIT_WORDS`].
+fn words_ending_with(byte: u8) -> &'static [usize] {
+    match byte {
+        b'e' => &[0, 2, 4, 8],
+        b'o' => &[1],
+        b'r' => &[3],
+        b'x' => &[5],
+        b'n' => &[6],
+        b't' => &[7],
+        _ => &[],
+    }
+}
+
+fn digit(byte: u8) -> Option<u64> {
+    byte.is_ascii_digit().then(|| u64::from(byte - b'0'))
+}
+
+pub fn first_digit(line: &[u8]) -> Option<u64> {
+    line.iter().find_map(|&byte| digit(byte))
+}
+
+pub fn last_digit(line: &[u8]) -> Option<u64> {
+    line.iter().rev().find_map(|&byte| digit(byte))
+}
+
+pub fn first_digit_or_word(line: &[u8]) -> Option<u64> {
+    (0..line.len()).find_map(|start| {
+        let rest = &line[start..];
+        digit(rest[0]).or_else(|| {
+            let index = words_starting_with(rest[0]).iter().find(|&&index| rest.starts_with(DIGIT_WORDS[index]))?;
+            Some(*index as u64 + 1)
+        })
+    })
+}
+
+pub fn last_digit_or_word(line: &[u8]) -> Option<u64> {
+    (1..=line.len()).rev().find_map(|end| {
+        let head = &line[..end];
+        digit(head[end - 1]).or_else(|| {
+            let index = words_ending_with(head[end - 1]).iter().find(|&&index| head.ends_with(DIGIT_WORDS[index]))?;
+            Some(*index as u64 + 1)
+        })
+    })
+}
+
+type Finder = fn(&[u8]) -> Option<u64>;
+
+fn line_value(line_index: usize, line: &[u8], first: Finder, last: Finder) -> Result<u64> {
+    match first(line).zip(last(line)) {
+        Some((first, last)) => Ok(first * 10 + last),
+        None => {
+            let text = String::from_utf8_lossy(line);
+            Err(PuzzleError::parser(line_index, &text, &text, "Line does not contain a digit!"))
+        }
+    }
+}
+
+/// Sums the calibration values of all lines of `input` without allocating, splitting the lines like [`str::lines`].
+///
+/// The end of every line is searched with `memchr`, which compares many bytes at once, and the line is then only
+/// scanned from both of its ends up to its first and last digit.
+fn sum_lines(input: &[u8], first: Finder, last: Finder) -> Result<u64> {
+    let mut sum = 0;
+    let mut start = 0;
+    let mut line_index = 0;
+    while start < input.len() {
+        let end = memchr(b'\n', &input[start..]).map_or(input.len(), |len| start + len);
+        let line = &input[start..end];
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        sum += line_value(line_index, line, first, last)?;
+        start = end + 1;
+        line_index += 1;
+    }
+    Ok(sum)
+}
+
+pub fn sum_digits(input: &[u8]) -> Result<u64> {
+    sum_lines(input, first_digit, last_digit)
+}
+
+pub fn sum_digits_and_words(input: &[u8]) -> Result<u64> {
+    sum_lines(input, first_digit_or_word, last_digit_or_word)
+}
+
+fn sum_lines_stream<R: BufRead>(reader: R, first: Finder, last: Finder) -> Result<u64> {
+    let mut sum = 0;
+    for_each_line(reader, |line_index, line| {
+        sum += line_value(line_index, line.as_bytes(), first, last)?;
+        Ok(())
+    })?;
+    Ok(sum)
+}
+
+pub fn sum_digits_stream<R: BufRead>(reader: R) -> Result<u64> {
+    sum_lines_stream(reader, first_digit, last_digit)
+}
+
+pub fn sum_digits_and_words_stream<R: BufRead>(reader: R) -> Result<u64> {
+    sum_lines_stream(reader, first_digit_or_word, last_digit_or_word)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenizer::Tokenizer;
+
+    #[test]
+    fn test_matches_tokenizer() {
+        let lines = ["eightwo", "xoneightx", "twone", "7pqrstsixteen", "nine", "a1b0c", "zoneight234", "sevenine", "3"];
+        for line in lines {
+            let digits = Tokenizer::digits().decode_line(line).map(|calibration| calibration.value);
+            let words = Tokenizer::digits_and_words().decode_line(line).map(|calibration| calibration.value);
+            assert_eq!(sum_digits(line.as_bytes()).ok(), digits, "{}", line);
+            assert_eq!(sum_digits_and_words(line.as_bytes()).ok(), words, "{}", line);
+        }
+    }
+
+    #[test]
+    fn test_line_endings() -> Result<()> {
+        assert_eq!(sum_digits(b"1a2\r\nb3\n")?, 45);
+        assert_eq!(sum_digits(b"1a2\nb3")?, 45);
+        assert_eq!(sum_digits(b"")?, 0);
+        let error = sum_digits_and_words(b"1\n\n2\n").unwrap_err();
+        assert!(matches!(error, PuzzleError::ParserError { line: 2, .. }));
+        Ok(())
+    }
+}
@@ -0,0 +1,146 @@
+use aoc_common::{PuzzleError, Result};
+
+use crate::matcher::{Match, Matcher};
+use crate::vocabulary::Vocabulary;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Digit,
+    Word,
+}
+
+/// Number found in a line, `offset` and `len` are counted in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub number: u32,
+    pub offset: usize,
+    pub len: usize,
+    pub kind: TokenKind,
+}
+
+/// First and last number of a line and the calibration value they form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Calibration {
+    pub first: Token,
+    pub last: Token,
+    pub value: u64,
+}
+
+/// Calibration value of the decimal digits of `first` followed by the ones of `last`, like `12` and `3` form `123`.
+fn concatenate(first: u32, last: u32) -> u64 {
+    let mut scale = 10;
+    while scale <= u64::from(last) {
+        scale *= 10;
+    }
+    u64::from(first) * scale + u64::from(last)
+}
+
+/// Finds the digits of a line and the spelled out numbers of a [`Vocabulary`].
+///
+/// The first number is searched from the start of the line and the last one from its end, so overlapping words like
+/// `eightwo` are recognized as both `8` and `2` without splitting the line into tokens.
+pub struct Tokenizer {
+    matcher: Matcher,
+    /// Number and kind of the token of every word of the matcher.
+    tokens: Vec<(u32, TokenKind)>,
+}
+
+impl Tokenizer {
+    pub fn new(vocabulary: &Vocabulary) -> Self {
+        let digits = (0..10).map(|digit| (digit.to_string(), digit, TokenKind::Digit));
+        let words = vocabulary.words().iter().map(|(word, number)| (word.clone(), *number, TokenKind::Word));
+        let (words, tokens): (Vec<String>, Vec<(u32, TokenKind)>) =
+            digits.chain(words).map(|(word, number, kind)| (word, (number, kind))).unzip();
+        Tokenizer { matcher: Matcher::new(&words), tokens }
+    }
+
+    pub fn digits() -> Self {
+        Self::new(&Vocabulary::none())
+    }
+
+    pub fn digits_and_words() -> Self {
+        Self::new(&Vocabulary::english())
+    }
+
+    fn token(&self, found: Match) -> Token {
+        let (number, kind) = self.tokens[found.index];
+        Token { number, offset: found.offset, len: found.len, kind }
+    }
+
+    pub fn first_token(&self, line: &str) -> Option<Token> {
+        self.matcher.find_first(line.as_bytes()).map(|found| self.token(found))
+    }
+
+    pub fn last_token(&self, line: &str) -> Option<Token> {
+        self.matcher.find_last(line.as_bytes()).map(|found| self.token(found))
+    }
+
+    /// Calibration of a line, `None` if the line does not contain a number.
+    pub fn decode_line(&self, line: &str) -> Option<Calibration> {
+        let first = self.first_token(line)?;
+        let last = self.last_token(line)?;
+        Some(Calibration { first, last, value: concatenate(first.number, last.number) })
+    }
+
+    pub fn decode_numbered_line(&self, line_index: usize, line: &str) -> Result<Calibration> {
+        self.decode_line(line)
+            .ok_or_else(|| PuzzleError::parser(line_index, line, line, "Line does not contain a digit!"))
+    }
+
+    /// Calibrations of all lines of the input.
+    pub fn decode(&self, input: &str) -> Result<Vec<Calibration>> {
+        input.lines().enumerate().map(|(line_index, line)| self.decode_numbered_line(line_index, line)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(tokenizer: &Tokenizer, line: &str) -> Option<(u32, u32, u64)> {
+        tokenizer.decode_line(line).map(|calibration| (calibration.first.number, calibration.last.number, calibration.value))
+    }
+
+    #[test]
+    fn test_overlapping_words() {
+        let tokenizer = Tokenizer::digits_and_words();
+        assert_eq!(values(&tokenizer, "eightwo"), Some((8, 2, 82)));
+        assert_eq!(values(&tokenizer, "xoneightx"), Some((1, 8, 18)));
+        assert_eq!(values(&tokenizer, "twone"), Some((2, 1, 21)));
+        assert_eq!(values(&tokenizer, "7pqrstsixteen"), Some((7, 6, 76)));
+        assert_eq!(values(&tokenizer, "nine"), Some((9, 9, 99)));
+    }
+
+    #[test]
+    fn test_digits_only() {
+        let tokenizer = Tokenizer::digits();
+        assert_eq!(values(&tokenizer, "eight2three"), Some((2, 2, 22)));
+        assert_eq!(values(&tokenizer, "a1b0c"), Some((1, 0, 10)));
+        assert_eq!(values(&tokenizer, "eightwo"), None);
+        assert_eq!(values(&tokenizer, ""), None);
+    }
+
+    #[test]
+    fn test_other_vocabularies() -> Result<()> {
+        assert_eq!(values(&Tokenizer::new(&Vocabulary::german()), "xfünfzweins"), Some((5, 1, 51)));
+        assert_eq!(values(&Tokenizer::new(&Vocabulary::french()), "septrois2"), Some((7, 2, 72)));
+        assert_eq!(values(&Tokenizer::new(&Vocabulary::roman()), "aVIIIbIX"), Some((8, 9, 89)));
+        assert_eq!(values(&Tokenizer::new(&Vocabulary::roman()), "X3X"), Some((10, 10, 1010)));
+        let vocabulary = Vocabulary::parse("twelve 12\neleven 11\n")?;
+        assert_eq!(values(&Tokenizer::new(&vocabulary), "twelve3eleven0"), Some((12, 0, 120)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_token_positions() {
+        let calibration = Tokenizer::digits_and_words().decode_line("äb3seveneight").unwrap();
+        assert_eq!(calibration.first, Token { number: 3, offset: 3, len: 1, kind: TokenKind::Digit });
+        assert_eq!(calibration.last, Token { number: 8, offset: 9, len: 5, kind: TokenKind::Word });
+    }
+
+    #[test]
+    fn test_decode_reports_line() {
+        let error = Tokenizer::digits().decode("1a\nabc\n2").unwrap_err();
+        assert!(matches!(error, PuzzleError::ParserError { line: 2, .. }));
+    }
+}
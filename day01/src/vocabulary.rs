@@ -0,0 +1,165 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use aoc_common::error::parse_number;
+use aoc_common::input::read_file;
+use aoc_common::{PuzzleError, Result};
+
+/// Largest number a word may stand for, so every calibration value fits into a `u64`.
+pub const MAX_NUMBER: u32 = 999_999_999;
+
+const ENGLISH: [(&str, u32); 9] =
+    [("one", 1), ("two", 2), ("three", 3), ("four", 4), ("five", 5), ("six", 6), ("seven", 7), ("eight", 8), ("nine", 9)];
+const GERMAN: [(&str, u32); 9] =
+    [("eins", 1), ("zwei", 2), ("drei", 3), ("vier", 4), ("fünf", 5), ("sechs", 6), ("sieben", 7), ("acht", 8), ("neun", 9)];
+const FRENCH: [(&str, u32); 9] =
+    [("un", 1), ("deux", 2), ("trois", 3), ("quatre", 4), ("cinq", 5), ("six", 6), ("sept", 7), ("huit", 8), ("neuf", 9)];
+const ROMAN: [(&str, u32); 10] =
+    [("I", 1), ("II", 2), ("III", 3), ("IV", 4), ("V", 5), ("VI", 6), ("VII", 7), ("VIII", 8), ("IX", 9), ("X", 10)];
+
+/// Names of the built in vocabularies, see [`Vocabulary::builtin`].
+pub const BUILTIN_NAMES: [&str; 4] = ["english", "german", "french", "roman"];
+
+/// Spelled out numbers recognized in the calibration lines besides the digits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+}
+
+fn check_word<'a>(word: &'a str, number: u32, seen: &mut HashSet<&'a str>) -> std::result::Result<(), &'static str> {
+    if word.is_empty() || word.chars().any(char::is_whitespace) {
+        return Err("Words must not be empty or contain whitespace!");
+    }
+    if word.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err("Words must not consist of digits only, they would replace the digits!");
+    }
+    if number > MAX_NUMBER {
+        return Err("Words may stand for numbers up to 999999999 only!");
+    }
+    if !seen.insert(word) {
+        return Err("Every word may only be listed once!");
+    }
+    Ok(())
+}
+
+impl Vocabulary {
+    /// Vocabulary of `words` and the numbers they stand for, which have to be unique and at most [`MAX_NUMBER`].
+    pub fn new(words: Vec<(String, u32)>) -> Result<Self> {
+        let mut seen = HashSet::new();
+        for (word, number) in words.iter() {
+            check_word(word, *number, &mut seen).map_err(|msg| PuzzleError::LogicError { msg })?;
+        }
+        Ok(Vocabulary { words })
+    }
+
+    fn from_table(table: &[(&str, u32)]) -> Self {
+        Vocabulary { words: table.iter().map(|(word, number)| (word.to_string(), *number)).collect() }
+    }
+
+    /// Only the digits, without any words.
+    pub fn none() -> Self {
+        Vocabulary { words: Vec::new() }
+    }
+
+    pub fn english() -> Self {
+        Self::from_table(&ENGLISH)
+    }
+
+    pub fn german() -> Self {
+        Self::from_table(&GERMAN)
+    }
+
+    pub fn french() -> Self {
+        Self::from_table(&FRENCH)
+    }
+
+    /// Upper case Roman numerals from `I` to `X`.
+    pub fn roman() -> Self {
+        Self::from_table(&ROMAN)
+    }
+
+    /// Built in vocabulary called `name`, one of [`BUILTIN_NAMES`].
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "english" => Some(Self::english()),
+            "german" => Some(Self::german()),
+            "french" => Some(Self::french()),
+            "roman" => Some(Self::roman()),
+            _ => None,
+        }
+    }
+
+    /// Parses a word list with one `<word> <number>` pair per line, ignoring empty lines and lines starting with `#`.
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut words = Vec::new();
+        let mut seen = HashSet::new();
+        for (line_index, line) in text.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            let mut parts = trimmed.split_whitespace();
+            let (Some(word), Some(number), None) = (parts.next(), parts.next(), parts.next()) else {
+                return Err(PuzzleError::parser(line_index, line, line, "Expected `<word> <number>`!"));
+            };
+            let number = parse_number(line_index, line, number)?;
+            check_word(word, number, &mut seen).map_err(|msg| PuzzleError::parser(line_index, line, word, msg))?;
+            words.push((word.to_string(), number));
+        }
+        Ok(Vocabulary { words })
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        Self::parse(&read_file(path)?)
+    }
+
+    /// Built in vocabulary called `name`, or the word list at the path `name` otherwise.
+    pub fn builtin_or_load(name: &str) -> Result<Self> {
+        match Self::builtin(name) {
+            Some(vocabulary) => Ok(vocabulary),
+            None => Self::load(Path::new(name)),
+        }
+    }
+
+    pub fn words(&self) -> &[(String, u32)] {
+        &self.words
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_word_list() -> Result<()> {
+        let vocabulary = Vocabulary::parse("# Practice round\nuno 1\n\n  dos   2\ndoce 12\n")?;
+        assert_eq!(vocabulary.words(), [("uno".to_string(), 1), ("dos".to_string(), 2), ("doce".to_string(), 12)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for (text, line) in [("uno 1\ndos\n", 2), ("uno x\n", 1), ("uno 1\nuno 2\n", 2), ("big 1000000000\n", 1), ("a 1 2\n", 1), ("uno 1\n7 3\n", 2)] {
+            let error = Vocabulary::parse(text).unwrap_err();
+            assert!(matches!(error, PuzzleError::ParserError { line: error_line, .. } if error_line == line), "{}: {}", text, error);
+        }
+    }
+
+    #[test]
+    fn test_builtin_vocabularies() {
+        for name in BUILTIN_NAMES {
+            let vocabulary = Vocabulary::builtin(name).unwrap();
+            assert!(Vocabulary::new(vocabulary.words().to_vec()).is_ok(), "{}", name);
+        }
+        assert!(Vocabulary::builtin("klingon").is_none());
+    }
+
+    #[test]
+    fn test_digit_words() {
+        for word in ["7", "42"] {
+            let error = Vocabulary::new(vec![(word.to_string(), 3)]).unwrap_err();
+            assert!(matches!(error, PuzzleError::LogicError { .. }), "{}", word);
+        }
+        assert!(Vocabulary::new(vec![("7a".to_string(), 3)]).is_ok());
+    }
+}
@@ -0,0 +1,122 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use day01::{
+    calculate_number, calculate_number_digits_only, calculate_number_digits_only_stream, calculate_number_stream,
+    calculate_number_with, calculate_number_with_stream, scan, MissingDigits, Report, Tokenizer, Vocabulary,
+};
+use proptest::prelude::*;
+
+const DIGIT_WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+fn digit_values(line: &str, with_words: bool) -> Vec<u64> {
+    (0..line.len())
+        .filter_map(|index| {
+            let rest = &line[index..];
+            let digit = rest.chars().next().and_then(|x| x.to_digit(10)).map(u64::from);
+            let word = DIGIT_WORDS
+                .iter()
+                .position(|word| with_words && rest.starts_with(word))
+                .map(|position| position as u64 + 1);
+            digit.or(word)
+        })
+        .collect()
+}
+
+fn calibration_value(line: &str, with_words: bool) -> u64 {
+    let values = digit_values(line, with_words);
+    values[0] * 10 + values[values.len() - 1]
+}
+
+/// Calibration lines made of letters, digits and spelled out digits, each containing at least one digit.
+fn calibration_lines() -> impl Strategy<Value = Vec<String>> {
+    let token = prop_oneof![
+        "[a-z]{1,4}",
+        "[1-9]",
+        prop::sample::select(DIGIT_WORDS.to_vec()).prop_map(String::from),
+    ];
+    let line = (prop::collection::vec(token, 0..8), "[1-9]", 0..8_usize).prop_map(|(mut tokens, digit, position)| {
+        tokens.insert(position.min(tokens.len()), digit);
+        tokens.concat()
+    });
+    prop::collection::vec(line, 1..20)
+}
+
+/// Calibration value with the words of `vocabulary`, preferring the longest of the words starting or ending at the
+/// same byte.
+fn vocabulary_value(line: &str, vocabulary: &[(String, u32)]) -> u64 {
+    let mut found: Vec<(usize, usize, u32)> = Vec::new();
+    for start in 0..line.len() {
+        if let Some(digit) = line[start..].chars().next().and_then(|x| x.to_digit(10)) {
+            found.push((start, 1, digit));
+        }
+        for (word, number) in vocabulary {
+            if line[start..].starts_with(word.as_str()) {
+                found.push((start, word.len(), *number));
+            }
+        }
+    }
+    let first = found.iter().min_by_key(|(start, len, _)| (*start, usize::MAX - len)).unwrap().2;
+    let last = found.iter().max_by_key(|(start, len, _)| (start + len, *len)).unwrap().2;
+    format!("{}{}", first, last).parse().unwrap()
+}
+
+/// Vocabularies of short words over a small alphabet, so the words often overlap and contain each other.
+fn vocabularies() -> impl Strategy<Value = Vec<(String, u32)>> {
+    prop::collection::btree_map("[abc]{1,4}", 0..1000_u32, 1..8).prop_map(|words| words.into_iter().collect())
+}
+
+fn vocabulary_lines() -> impl Strategy<Value = Vec<String>> {
+    prop::collection::vec(("[abc]{0,6}", "[0-9]", "[abc]{0,6}").prop_map(|(a, digit, b)| a + &digit + &b), 1..20)
+}
+
+/// Arbitrary lines, often without any digit and with spelled out digits cut short or sharing their letters.
+fn arbitrary_lines() -> impl Strategy<Value = Vec<String>> {
+    let token = prop_oneof![
+        "[a-z]{1,3}",
+        "[0-9]",
+        "\\PC{1,2}",
+        prop::sample::select(DIGIT_WORDS.to_vec()).prop_map(|word| word[..word.len() - 1].to_string()),
+        prop::sample::select(DIGIT_WORDS.to_vec()).prop_map(String::from),
+    ];
+    prop::collection::vec(prop::collection::vec(token, 0..6).prop_map(|tokens| tokens.concat()), 0..10)
+}
+
+proptest! {
+    #[test]
+    fn digits_only_matches_reference(lines in calibration_lines()) {
+        let expected: u64 = lines.iter().map(|line| calibration_value(line, false)).sum();
+        let input = lines.join("\n");
+        prop_assert_eq!(calculate_number_digits_only(&input)?, expected);
+        prop_assert_eq!(calculate_number_digits_only_stream(input.as_bytes())?, expected);
+    }
+
+    #[test]
+    fn spelled_out_digits_match_reference(lines in calibration_lines()) {
+        let expected: u64 = lines.iter().map(|line| calibration_value(line, true)).sum();
+        let input = lines.join("\n");
+        prop_assert_eq!(calculate_number(&input)?, expected);
+        prop_assert_eq!(calculate_number_stream(input.as_bytes())?, expected);
+    }
+
+    #[test]
+    fn vocabulary_matches_reference(words in vocabularies(), lines in vocabulary_lines()) {
+        let expected: u64 = lines.iter().map(|line| vocabulary_value(line, &words)).sum();
+        let vocabulary = Vocabulary::new(words)?;
+        let input = lines.join("\n");
+        prop_assert_eq!(calculate_number_with(&input, &vocabulary)?, expected);
+        prop_assert_eq!(calculate_number_with_stream(input.as_bytes(), &vocabulary)?, expected);
+    }
+
+    #[test]
+    fn scan_matches_tokenizer(lines in arbitrary_lines()) {
+        let input = lines.join("\n");
+        for (scan, tokenizer) in [
+            (scan::sum_digits(input.as_bytes()), Tokenizer::digits()),
+            (scan::sum_digits_and_words(input.as_bytes()), Tokenizer::digits_and_words()),
+        ] {
+            let report = Report::collect(&input, &tokenizer, MissingDigits::Fail);
+            prop_assert_eq!(scan.as_ref().ok(), report.as_ref().ok().map(|report| &report.sum));
+            prop_assert_eq!(scan.err().map(|error| error.to_string()), report.err().map(|error| error.to_string()));
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use day02::{get_games_power_sum, get_valid_games_id_sum, parse_input};
+
+const INPUT: &str = include_str!("../resources/input.txt");
+
+fn bench_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day02/parse");
+    group.bench_function("parse_input", |b| b.iter(|| parse_input(black_box(INPUT))));
+    group.finish();
+}
+
+fn bench_solve(c: &mut Criterion) {
+    let games = parse_input(INPUT).expect("Bundled input is valid");
+
+    let mut group = c.benchmark_group("day02/solve");
+    group.bench_function("part1", |b| b.iter(|| get_valid_games_id_sum(black_box(&games), 12, 14, 13)));
+    group.bench_function("part2", |b| b.iter(|| get_games_power_sum(black_box(&games))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse, bench_solve);
+criterion_main!(benches);
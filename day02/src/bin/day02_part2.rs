@@ -1,81 +1,16 @@
-use regex::Regex;
-use std::str::FromStr;
+use aoc_common::input::read_input;
+use aoc_common::Solution;
+use anyhow::Result;
+use day02::{Day02, get_games_power_sum, parse_input};
 
-#[derive(Default)]
-struct Round {
-    red: u64,
-    blue: u64,
-    green: u64,
-}
-
-#[derive(Default)]
-struct Game {
-    rounds: Vec<Round>,
-}
-
-fn parse_input(inputs: Vec<String>) -> Vec<Game> {
-    let red_re = Regex::new(r"(?P<red>\d+)\sred").unwrap();
-    let green_re = Regex::new(r"(?P<green>\d+)\sgreen").unwrap();
-    let blue_re = Regex::new(r"(?P<blue>\d+)\sblue").unwrap();
-
-    let result: Vec<Game> = inputs
-        .iter()
-        .map(|input| {
-            let (_, rounds_str) = input.split_once(':').unwrap();
-
-            let rounds: Vec<Round> = rounds_str
-                .split_terminator(';')
-                .map(|x| {
-                    let red_cubes: u64 = match red_re.captures(x) {
-                        None => 0,
-                        Some(captures) => u64::from_str(&captures["red"]).unwrap(),
-                    };
-                    let blue_cubes: u64 = match blue_re.captures(x) {
-                        None => 0,
-                        Some(captures) => u64::from_str(&captures["blue"]).unwrap(),
-                    };
-                    let green_cubes: u64 = match green_re.captures(x) {
-                        None => 0,
-                        Some(captures) => u64::from_str(&captures["green"]).unwrap(),
-                    };
-
-                    Round {
-                        red: red_cubes,
-                        blue: blue_cubes,
-                        green: green_cubes,
-                    }
-                })
-                .collect();
-
-            Game {
-                rounds,
-            }
-        })
-        .collect();
-    result
-}
-
-fn get_result(games: Vec<Game>) -> u64 {
-    let mut result: u64 = 0;
-    for game in games {
-        let max_red = game.rounds.iter().map(|x| x.red).max().unwrap();
-        let max_green = game.rounds.iter().map(|x| x.green).max().unwrap();
-        let max_blue = game.rounds.iter().map(|x| x.blue).max().unwrap();
-        result += max_red * max_green * max_blue;
-    }
-    result
-}
-
-fn main() {
-    let input: Vec<String> = include_str!("../../resources/input.txt")
-        .lines()
-        .map(str::to_string)
-        .collect();
+fn main() -> Result<()> {
+    let input = read_input(Day02::DAY)?;
 
-    let games = parse_input(input);
-    let res = get_result(games);
+    let games = parse_input(&input)?;
+    let res = get_games_power_sum(&games)?;
 
     println!("{}", res);
+    Ok(())
 }
 
 #[cfg(test)]
@@ -83,14 +18,12 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_input() {
-        let input: Vec<String> = include_str!("../../resources/test_input01.txt")
-            .lines()
-            .map(str::to_string)
-            .collect();
+    fn test_input() -> Result<()> {
+        let input = include_str!("../../resources/test_input01.txt");
 
-        let games = parse_input(input);
-        let res = get_result(games);
+        let games = parse_input(input)?;
+        let res = get_games_power_sum(&games)?;
         assert_eq!(res, 2286);
+        Ok(())
     }
 }
@@ -0,0 +1,174 @@
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::sync::LazyLock;
+
+use aoc_common::error::parse_number;
+use aoc_common::stream::for_each_line;
+use aoc_common::{PuzzleError, Result, Solution, StreamingSolution};
+use regex::Regex;
+
+#[derive(Default, Debug)]
+pub struct Round {
+    red: u64,
+    blue: u64,
+    green: u64,
+}
+
+#[derive(Default, Debug)]
+pub struct Game {
+    id: u64,
+    rounds: Vec<Round>,
+}
+
+impl Display for Round {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let cubes: Vec<String> = [(self.red, "red"), (self.green, "green"), (self.blue, "blue")]
+            .iter()
+            .filter(|(count, _)| *count > 0)
+            .map(|(count, color)| format!("{} {}", count, color))
+            .collect();
+        write!(f, "{}", cubes.join(", "))
+    }
+}
+
+impl Display for Game {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let rounds: Vec<String> = self.rounds.iter().map(|round| round.to_string()).collect();
+        write!(f, "Game {}: {}", self.id, rounds.join("; "))
+    }
+}
+
+static GAME_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^Game\s(\d+)$").unwrap());
+static RED_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(\d+)\sred").unwrap());
+static GREEN_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(\d+)\sgreen").unwrap());
+static BLUE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(\d+)\sblue").unwrap());
+
+fn parse_cubes(line_index: usize, line: &str, round: &str, color_re: &Regex) -> Result<u64> {
+    match color_re.captures(round).and_then(|captures| captures.get(1)) {
+        None => Ok(0),
+        Some(count) => parse_number(line_index, line, count.as_str()),
+    }
+}
+
+fn parse_game(line_index: usize, line: &str) -> Result<Game> {
+    let (game_id_str, rounds_str) = line
+        .split_once(':')
+        .ok_or_else(|| PuzzleError::parser(line_index, line, line, "Missing `:` after the game id!"))?;
+    let game_id_match = GAME_RE
+        .captures(game_id_str)
+        .and_then(|captures| captures.get(1))
+        .ok_or_else(|| PuzzleError::parser(line_index, line, game_id_str, "Expected `Game <id>`!"))?;
+    let game_id = parse_number(line_index, line, game_id_match.as_str())?;
+
+    let rounds = rounds_str
+        .split_terminator(';')
+        .map(|x| {
+            Ok(Round {
+                red: parse_cubes(line_index, line, x, &RED_RE)?,
+                blue: parse_cubes(line_index, line, x, &BLUE_RE)?,
+                green: parse_cubes(line_index, line, x, &GREEN_RE)?,
+            })
+        })
+        .collect::<Result<Vec<Round>>>()?;
+
+    Ok(Game {
+        id: game_id,
+        rounds,
+    })
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Game>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_index, line)| parse_game(line_index, line))
+        .collect()
+}
+
+fn check_valid(game: &Game, max_red: u64, max_blue: u64, max_green: u64) -> bool {
+    game.rounds
+        .iter()
+        .all(|round| round.red <= max_red && round.green <= max_green && round.blue <= max_blue)
+}
+
+const OVERFLOW: PuzzleError = PuzzleError::LogicError { msg: "Sum does not fit into 64 bits!" };
+
+fn add(sum: u64, value: u64) -> Result<u64> {
+    sum.checked_add(value).ok_or(OVERFLOW)
+}
+
+pub fn get_valid_games_id_sum(games: &[Game], max_red: u64, max_blue: u64, max_green: u64) -> Result<u64> {
+    games
+        .iter()
+        .filter(|&game| check_valid(game, max_red, max_blue, max_green))
+        .try_fold(0, |sum, game| add(sum, game.id))
+}
+
+fn game_power(game: &Game) -> Result<u64> {
+    let max_red = game.rounds.iter().map(|x| x.red).max().unwrap_or(0);
+    let max_green = game.rounds.iter().map(|x| x.green).max().unwrap_or(0);
+    let max_blue = game.rounds.iter().map(|x| x.blue).max().unwrap_or(0);
+    max_red
+        .checked_mul(max_green)
+        .and_then(|power| power.checked_mul(max_blue))
+        .ok_or(PuzzleError::LogicError { msg: "Power of the game does not fit into 64 bits!" })
+}
+
+pub fn get_games_power_sum(games: &[Game]) -> Result<u64> {
+    games.iter().try_fold(0, |sum, game| add(sum, game_power(game)?))
+}
+
+pub fn get_valid_games_id_sum_stream<R: BufRead>(reader: R, max_red: u64, max_blue: u64, max_green: u64) -> Result<u64> {
+    let mut sum = 0_u64;
+    for_each_line(reader, |line_index, line| {
+        let game = parse_game(line_index, line)?;
+        if check_valid(&game, max_red, max_blue, max_green) {
+            sum = add(sum, game.id)?;
+        }
+        Ok(())
+    })?;
+    Ok(sum)
+}
+
+pub fn get_games_power_sum_stream<R: BufRead>(reader: R) -> Result<u64> {
+    let mut sum = 0_u64;
+    for_each_line(reader, |line_index, line| {
+        sum = add(sum, game_power(&parse_game(line_index, line)?)?)?;
+        Ok(())
+    })?;
+    Ok(sum)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input<'a> = Vec<Game>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1> {
+        get_valid_games_id_sum(input, 12, 14, 13)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2> {
+        get_games_power_sum(input)
+    }
+}
+
+impl StreamingSolution for Day02 {
+    fn part1_stream(reader: &mut dyn BufRead) -> Result<Self::Answer1> {
+        get_valid_games_id_sum_stream(reader, 12, 14, 13)
+    }
+
+    fn part2_stream(reader: &mut dyn BufRead) -> Result<Self::Answer2> {
+        get_games_power_sum_stream(reader)
+    }
+}
+
+#[cfg(feature = "wasm")]
+aoc_common::wasm_exports!(Day02);
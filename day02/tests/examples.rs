@@ -0,0 +1,14 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use aoc_common::examples::{assert_examples, assert_stream_examples};
+use day02::Day02;
+
+#[test]
+fn examples() {
+    assert_examples::<Day02>(concat!(env!("CARGO_MANIFEST_DIR"), "/resources"));
+}
+
+#[test]
+fn stream_examples() {
+    assert_stream_examples::<Day02>(concat!(env!("CARGO_MANIFEST_DIR"), "/resources"));
+}
@@ -0,0 +1,69 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use day02::{get_games_power_sum, get_games_power_sum_stream, get_valid_games_id_sum, get_valid_games_id_sum_stream, parse_input};
+use proptest::prelude::*;
+
+/// Rounds as `(red, green, blue)` counts with at least one cube drawn.
+fn games() -> impl Strategy<Value = Vec<Vec<(u64, u64, u64)>>> {
+    let round = (0..20_u64, 0..20_u64, 0..20_u64).prop_filter("a round draws cubes", |(red, green, blue)| red + green + blue > 0);
+    prop::collection::vec(prop::collection::vec(round, 1..6), 1..20)
+}
+
+fn render(games: &[Vec<(u64, u64, u64)>]) -> String {
+    games
+        .iter()
+        .enumerate()
+        .map(|(index, rounds)| {
+            let rounds: Vec<String> = rounds
+                .iter()
+                .map(|(red, green, blue)| {
+                    let cubes: Vec<String> = [(red, "red"), (green, "green"), (blue, "blue")]
+                        .iter()
+                        .filter(|(count, _)| **count > 0)
+                        .map(|(count, color)| format!("{} {}", count, color))
+                        .collect();
+                    cubes.join(", ")
+                })
+                .collect();
+            format!("Game {}: {}", index + 1, rounds.join("; "))
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+proptest! {
+    #[test]
+    fn parse_display_roundtrip(games in games()) {
+        let input = render(&games);
+        let parsed = parse_input(&input)?;
+        let output: Vec<String> = parsed.iter().map(|game| game.to_string()).collect();
+        prop_assert_eq!(output.join("\n"), input);
+    }
+
+    #[test]
+    fn solvers_match_reference(games in games(), max_red in 0..20_u64, max_green in 0..20_u64, max_blue in 0..20_u64) {
+        let input = render(&games);
+        let parsed = parse_input(&input)?;
+
+        let expected_id_sum: u64 = games
+            .iter()
+            .enumerate()
+            .filter(|(_, rounds)| rounds.iter().all(|(red, green, blue)| *red <= max_red && *green <= max_green && *blue <= max_blue))
+            .map(|(index, _)| index as u64 + 1)
+            .sum();
+        prop_assert_eq!(get_valid_games_id_sum(&parsed, max_red, max_blue, max_green)?, expected_id_sum);
+        prop_assert_eq!(get_valid_games_id_sum_stream(input.as_bytes(), max_red, max_blue, max_green)?, expected_id_sum);
+
+        let expected_power_sum: u64 = games
+            .iter()
+            .map(|rounds| {
+                let red = rounds.iter().map(|round| round.0).max().unwrap_or(0);
+                let green = rounds.iter().map(|round| round.1).max().unwrap_or(0);
+                let blue = rounds.iter().map(|round| round.2).max().unwrap_or(0);
+                red * green * blue
+            })
+            .sum();
+        prop_assert_eq!(get_games_power_sum(&parsed)?, expected_power_sum);
+        prop_assert_eq!(get_games_power_sum_stream(input.as_bytes())?, expected_power_sum);
+    }
+}
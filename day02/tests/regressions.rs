@@ -0,0 +1,31 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use aoc_common::{PuzzleError, Result};
+use day02::{get_games_power_sum, get_games_power_sum_stream, get_valid_games_id_sum, get_valid_games_id_sum_stream, parse_input};
+
+fn is_overflow(result: Result<u64>) -> bool {
+    matches!(result, Err(PuzzleError::LogicError { .. }))
+}
+
+/// The power of a game with many cubes and the sum of the powers used to overflow and panic.
+#[test]
+fn overflowing_power_fails() -> Result<()> {
+    let input = "Game 1: 9999999999 red, 9999999999 green, 9999999999 blue\n";
+    assert!(is_overflow(get_games_power_sum(&parse_input(input)?)));
+    assert!(is_overflow(get_games_power_sum_stream(input.as_bytes())));
+    assert_eq!(get_games_power_sum(&parse_input("Game 1: 4294967296 red, 4294967295 green, 1 blue\n")?)?, u64::MAX - u64::from(u32::MAX));
+    let input = "Game 1: 4294967296 red, 4294967295 green, 1 blue\nGame 2: 4294967296 red, 1 green, 1 blue\n";
+    assert!(is_overflow(get_games_power_sum(&parse_input(input)?)));
+    assert!(is_overflow(get_games_power_sum_stream(input.as_bytes())));
+    Ok(())
+}
+
+/// The sum of large game ids used to overflow and panic.
+#[test]
+fn overflowing_id_sum_fails() -> Result<()> {
+    let input = "Game 18446744073709551615: 1 red\nGame 1: 1 blue\n";
+    assert!(is_overflow(get_valid_games_id_sum(&parse_input(input)?, 12, 14, 13)));
+    assert!(is_overflow(get_valid_games_id_sum_stream(input.as_bytes(), 12, 14, 13)));
+    assert_eq!(get_valid_games_id_sum(&parse_input("Game 18446744073709551615: 1 red\n")?, 12, 14, 13)?, u64::MAX);
+    Ok(())
+}
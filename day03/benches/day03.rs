@@ -0,0 +1,24 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use day03::{get_gear_ratios_sum, get_part_numbers_sum, get_positions};
+
+const INPUT: &str = include_str!("../resources/input.txt");
+
+fn bench_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day03/parse");
+    group.bench_function("get_positions", |b| b.iter(|| get_positions(black_box(INPUT))));
+    group.finish();
+}
+
+fn bench_solve(c: &mut Criterion) {
+    let (numbers, symbols) = get_positions(INPUT).expect("Bundled input is valid");
+
+    let mut group = c.benchmark_group("day03/solve");
+    group.bench_function("part1", |b| b.iter(|| get_part_numbers_sum(black_box(&numbers), black_box(&symbols))));
+    group.bench_function("part2", |b| b.iter(|| get_gear_ratios_sum(black_box(&numbers), black_box(&symbols))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse, bench_solve);
+criterion_main!(benches);
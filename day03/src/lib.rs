@@ -0,0 +1,151 @@
+use aoc_common::error::parse_number;
+use aoc_common::{PuzzleError, Result, Solution};
+
+#[derive(Debug, Default)]
+pub struct Number {
+    y: usize,
+    start_x: usize,
+    end_x: usize,
+    value: u64,
+}
+
+#[derive(Default, Debug)]
+pub struct Symbol {
+    y: usize,
+    x: usize,
+    value: char,
+}
+
+impl Symbol {
+    pub fn is_gear(&self) -> bool {
+        self.value == '*'
+    }
+}
+
+pub fn get_positions(input: &str) -> Result<(Vec<Number>, Vec<Symbol>)> {
+    let mut numbers: Vec<Number> = Vec::new();
+    let mut symbols: Vec<Symbol> = Vec::new();
+
+    for (line_index, line) in input.lines().enumerate() {
+        let mut current_number_start_index: Option<usize> = None;
+        for (index, char) in line.char_indices() {
+            if char.is_ascii_digit() {
+                if current_number_start_index.is_none() {
+                    current_number_start_index = Some(index);
+                }
+                continue;
+            }
+
+            if let Some(start_index) = current_number_start_index {
+                numbers.push(Number {
+                    y: line_index,
+                    start_x: start_index,
+                    end_x: index - 1,
+                    value: parse_number(line_index, line, &line[start_index..index])?,
+                });
+                current_number_start_index = None;
+            }
+
+            if char != '.' {
+                symbols.push(Symbol {
+                    y: line_index,
+                    x: index,
+                    value: char,
+                });
+            }
+        }
+
+        if let Some(start_index) = current_number_start_index {
+            numbers.push(Number {
+                y: line_index,
+                start_x: start_index,
+                end_x: line.len() - 1,
+                value: parse_number(line_index, line, &line[start_index..])?,
+            });
+        }
+    }
+
+    Ok((numbers, symbols))
+}
+
+fn is_symbol_neighbor(number: &Number, symbol_covered_positions: &[(usize, usize)]) -> bool {
+    (number.start_x..number.end_x + 1).any(|idx| symbol_covered_positions.contains(&(idx, number.y)))
+}
+
+fn get_symbol_covered_positions(symbol: &Symbol) -> Vec<(usize, usize)> {
+    let mut results: Vec<(usize, usize)> = Vec::with_capacity(9);
+
+    let opt_idx_n1 = usize::checked_sub(symbol.x, 1);
+    let idx_0 = symbol.x;
+    let idx_p1 = symbol.x + 1;
+
+    let opt_idy_n1 = usize::checked_sub(symbol.y, 1);
+    let idy_0 = symbol.y;
+    let idy_p1 = symbol.y + 1;
+
+    if let Some(idy_n1) = opt_idy_n1 {
+        results.extend_from_slice(&[(idx_0, idy_n1), (idx_p1, idy_n1)]);
+    }
+    if let Some(idx_n1) = opt_idx_n1 {
+        results.extend_from_slice(&[(idx_n1, idy_0), (idx_n1, idy_p1)]);
+    }
+    if let (Some(idx_n1), Some(idy_n1)) = (opt_idx_n1, opt_idy_n1) {
+        results.push((idx_n1, idy_n1));
+    }
+    results.extend_from_slice(&[(idx_0, idy_p1), (idx_p1, idy_p1), (idx_p1, idy_0)]);
+
+    results
+}
+
+const OVERFLOW: PuzzleError = PuzzleError::LogicError { msg: "Sum does not fit into 64 bits!" };
+
+pub fn get_part_numbers_sum(numbers: &[Number], symbols: &[Symbol]) -> Result<u64> {
+    let symbol_positions: Vec<(usize, usize)> = symbols.iter().flat_map(get_symbol_covered_positions).collect();
+    numbers
+        .iter()
+        .filter(|&x| is_symbol_neighbor(x, &symbol_positions))
+        .try_fold(0_u64, |sum, x| sum.checked_add(x.value))
+        .ok_or(OVERFLOW)
+}
+
+pub fn get_gear_ratios_sum(numbers: &[Number], symbols: &[Symbol]) -> Result<u64> {
+    let mut result: u64 = 0;
+    for gear in symbols.iter().filter(|x| x.is_gear()) {
+        let gear_covered_positions = get_symbol_covered_positions(gear);
+        let gear_values: Vec<u64> = numbers
+            .iter()
+            .filter(|&x| is_symbol_neighbor(x, &gear_covered_positions))
+            .map(|x| x.value)
+            .collect();
+        if gear_values.len() != 2 {
+            continue;
+        }
+        let ratio = gear_values[0].checked_mul(gear_values[1]).ok_or(OVERFLOW)?;
+        result = result.checked_add(ratio).ok_or(OVERFLOW)?;
+    }
+    Ok(result)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input<'a> = (Vec<Number>, Vec<Symbol>);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        get_positions(input)
+    }
+
+    fn part1((numbers, symbols): &Self::Input<'_>) -> Result<Self::Answer1> {
+        get_part_numbers_sum(numbers, symbols)
+    }
+
+    fn part2((numbers, symbols): &Self::Input<'_>) -> Result<Self::Answer2> {
+        get_gear_ratios_sum(numbers, symbols)
+    }
+}
+
+#[cfg(feature = "wasm")]
+aoc_common::wasm_exports!(Day03);
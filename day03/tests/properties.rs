@@ -0,0 +1,46 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use day03::{get_gear_ratios_sum, get_part_numbers_sum, get_positions};
+use proptest::prelude::*;
+
+/// Rows of numbers, each number is followed by `separator` and a gap of dots.
+fn render(rows: &[Vec<(u64, usize)>], separator: char) -> String {
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .map(|(number, gap)| format!("{}{}{}", number, separator, ".".repeat(*gap)))
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn rows() -> impl Strategy<Value = Vec<Vec<(u64, usize)>>> {
+    prop::collection::vec(prop::collection::vec((1..1000_u64, 1..4_usize), 0..6), 1..10)
+}
+
+proptest! {
+    #[test]
+    fn numbers_without_symbols_are_no_part_numbers(rows in rows()) {
+        let (numbers, symbols) = get_positions(&render(&rows, '.'))?;
+        prop_assert_eq!(numbers.len(), rows.iter().map(Vec::len).sum::<usize>());
+        prop_assert!(symbols.is_empty());
+        prop_assert_eq!(get_part_numbers_sum(&numbers, &symbols)?, 0);
+    }
+
+    #[test]
+    fn numbers_next_to_symbols_are_part_numbers(rows in rows()) {
+        let (numbers, symbols) = get_positions(&render(&rows, '#'))?;
+        let expected: u64 = rows.iter().flatten().map(|(number, _)| number).sum();
+        prop_assert_eq!(get_part_numbers_sum(&numbers, &symbols)?, expected);
+        prop_assert_eq!(get_gear_ratios_sum(&numbers, &symbols)?, 0);
+    }
+
+    #[test]
+    fn gears_multiply_their_two_numbers(pairs in prop::collection::vec((1..1000_u64, 1..1000_u64), 1..10)) {
+        let input: Vec<String> = pairs.iter().map(|(left, right)| format!("{}*{}", left, right)).collect();
+        let (numbers, symbols) = get_positions(&input.join("\n\n"))?;
+        let expected: u64 = pairs.iter().map(|(left, right)| left * right).sum();
+        prop_assert_eq!(get_gear_ratios_sum(&numbers, &symbols)?, expected);
+    }
+}
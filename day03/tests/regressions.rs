@@ -0,0 +1,16 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use aoc_common::{PuzzleError, Result};
+use day03::{get_gear_ratios_sum, get_part_numbers_sum, get_positions};
+
+/// Sums and gear ratios of large part numbers used to overflow and panic, found by the fuzz target.
+#[test]
+fn overflowing_sums_fail() -> Result<()> {
+    let (numbers, symbols) = get_positions("9999999999999999999*9999999999999999999\n")?;
+    assert!(matches!(get_part_numbers_sum(&numbers, &symbols), Err(PuzzleError::LogicError { .. })));
+    assert!(matches!(get_gear_ratios_sum(&numbers, &symbols), Err(PuzzleError::LogicError { .. })));
+    let (numbers, symbols) = get_positions("5555555555555555555*20\n")?;
+    assert_eq!(get_part_numbers_sum(&numbers, &symbols)?, 5555555555555555575);
+    assert!(matches!(get_gear_ratios_sum(&numbers, &symbols), Err(PuzzleError::LogicError { .. })));
+    Ok(())
+}
@@ -0,0 +1,150 @@
+use std::collections::{HashSet, VecDeque};
+use std::io::BufRead;
+use aoc_common::error::parse_number;
+use aoc_common::stream::for_each_line;
+use aoc_common::{PuzzleError, Result, Solution, StreamingSolution};
+
+pub struct Card {
+    winning_numbers: HashSet<u64>,
+    present_numbers: HashSet<u64>
+}
+
+fn parse_numbers(line_index: usize, line: &str, numbers: &str) -> Result<HashSet<u64>> {
+    numbers.split_whitespace().map(|x| parse_number(line_index, line, x)).collect()
+}
+
+fn parse_card(line_index: usize, line: &str) -> Result<Card> {
+    let splits: Vec<&str> = line.split_terminator(&[':', '|'][..]).collect();
+    if splits.len() != 3 {
+        return Err(PuzzleError::parser(line_index, line, line, "Expected `Card <id>: <winning numbers> | <numbers>`!"));
+    }
+
+    let winning_numbers = parse_numbers(line_index, line, splits[1])?;
+    let present_numbers = parse_numbers(line_index, line, splits[2])?;
+    Ok(Card { winning_numbers, present_numbers})
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Card>> {
+    input.lines().enumerate().map(|(line_index, line)| parse_card(line_index, line)).collect()
+}
+
+fn get_matching_numbers_count(card: &Card) -> usize {
+    card.present_numbers.intersection(&card.winning_numbers).count()
+}
+
+const POINTS_OVERFLOW: PuzzleError = PuzzleError::LogicError { msg: "Points do not fit into 64 bits!" };
+
+fn calculate_card_value(card: &Card) -> Result<u64> {
+    let correct_winning_numbers_count = get_matching_numbers_count(card);
+    if correct_winning_numbers_count == 0 {
+        return Ok(0)
+    }
+    u32::try_from(correct_winning_numbers_count - 1)
+        .ok()
+        .and_then(|shift| 1_u64.checked_shl(shift))
+        .ok_or(POINTS_OVERFLOW)
+}
+
+fn add_points(points: u64, card: &Card) -> Result<u64> {
+    points.checked_add(calculate_card_value(card)?).ok_or(POINTS_OVERFLOW)
+}
+
+pub fn calculate_points(cards: &[Card]) -> Result<u64> {
+    cards.iter().try_fold(0, add_points)
+}
+
+const CARD_COUNT_OVERFLOW: PuzzleError = PuzzleError::LogicError { msg: "Card count does not fit into 64 bits!" };
+
+fn add_copies(copies: &mut u64, count: u64) -> Result<()> {
+    *copies = copies.checked_add(count).ok_or(CARD_COUNT_OVERFLOW)?;
+    Ok(())
+}
+
+pub fn calculate_card_count(cards: &[Card]) -> Result<u64> {
+    let no_of_cards: usize = cards.len();
+    let card_wins: Vec<usize> = cards.iter().map(get_matching_numbers_count).collect();
+    let mut card_count: Vec<u64> = vec![1; no_of_cards];
+
+    let mut result: u64 = 0;
+
+    for idx in 0..no_of_cards {
+        let wins = card_wins[idx];
+        let count = card_count[idx];
+
+        let won_cards = card_count
+            .get_mut(idx + 1..idx + wins + 1)
+            .ok_or(PuzzleError::LogicError { msg: "Card wins copies of cards past the end of the table!" })?;
+        for idy in won_cards {
+            add_copies(idy, count)?;
+        }
+        add_copies(&mut result, count)?;
+    }
+    Ok(result)
+}
+
+pub fn calculate_points_stream<R: BufRead>(reader: R) -> Result<u64> {
+    let mut points = 0;
+    for_each_line(reader, |line_index, line| {
+        points = add_points(points, &parse_card(line_index, line)?)?;
+        Ok(())
+    })?;
+    Ok(points)
+}
+
+pub fn calculate_card_count_stream<R: BufRead>(reader: R) -> Result<u64> {
+    // Copies won of the following cards, a card only wins copies of as many cards as it has winning numbers.
+    let mut won_copies: VecDeque<u64> = VecDeque::new();
+    let mut result: u64 = 0;
+    for_each_line(reader, |line_index, line| {
+        let wins = get_matching_numbers_count(&parse_card(line_index, line)?);
+        let mut count = 1;
+        add_copies(&mut count, won_copies.pop_front().unwrap_or(0))?;
+        if won_copies.len() < wins {
+            won_copies.resize(wins, 0);
+        }
+        for copies in won_copies.iter_mut().take(wins) {
+            add_copies(copies, count)?;
+        }
+        add_copies(&mut result, count)?;
+        Ok(())
+    })?;
+
+    if !won_copies.is_empty() {
+        return Err(PuzzleError::LogicError { msg: "Card wins copies of cards past the end of the table!" });
+    }
+    Ok(result)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input<'a> = Vec<Card>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1> {
+        calculate_points(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2> {
+        calculate_card_count(input)
+    }
+}
+
+impl StreamingSolution for Day04 {
+    fn part1_stream(reader: &mut dyn BufRead) -> Result<Self::Answer1> {
+        calculate_points_stream(reader)
+    }
+
+    fn part2_stream(reader: &mut dyn BufRead) -> Result<Self::Answer2> {
+        calculate_card_count_stream(reader)
+    }
+}
+
+#[cfg(feature = "wasm")]
+aoc_common::wasm_exports!(Day04);
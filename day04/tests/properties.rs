@@ -0,0 +1,64 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use std::collections::BTreeSet;
+
+use day04::{calculate_card_count, calculate_card_count_stream, calculate_points, calculate_points_stream, parse_input};
+use proptest::prelude::*;
+
+fn cards() -> impl Strategy<Value = Vec<(BTreeSet<u64>, BTreeSet<u64>)>> {
+    let numbers = || prop::collection::btree_set(1..30_u64, 1..8);
+    prop::collection::vec((numbers(), numbers()), 1..15)
+}
+
+fn render(cards: &[(BTreeSet<u64>, BTreeSet<u64>)]) -> String {
+    let join = |numbers: &BTreeSet<u64>| numbers.iter().map(|number| format!("{:>2}", number)).collect::<Vec<String>>().join(" ");
+    cards
+        .iter()
+        .enumerate()
+        .map(|(index, (winning, present))| format!("Card {:>3}: {} | {}", index + 1, join(winning), join(present)))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+proptest! {
+    #[test]
+    fn points_match_reference(cards in cards()) {
+        let input = render(&cards);
+        let parsed = parse_input(&input)?;
+        let expected: u64 = cards
+            .iter()
+            .map(|(winning, present)| winning.intersection(present).count() as u32)
+            .map(|matches| if matches == 0 { 0 } else { 2_u64.pow(matches - 1) })
+            .sum();
+        prop_assert_eq!(calculate_points(&parsed)?, expected);
+        prop_assert_eq!(calculate_points_stream(input.as_bytes())?, expected);
+    }
+
+    #[test]
+    fn card_count_matches_reference(cards in cards()) {
+        let input = render(&cards);
+        let parsed = parse_input(&input)?;
+        let mut copies = vec![1_u64; cards.len()];
+        let mut fits_table = true;
+        for (index, (winning, present)) in cards.iter().enumerate() {
+            let matches = winning.intersection(present).count();
+            if matches > 0 && index + matches >= cards.len() {
+                fits_table = false;
+                break;
+            }
+            for won in index + 1..=index + matches {
+                copies[won] += copies[index];
+            }
+        }
+
+        for result in [calculate_card_count(&parsed), calculate_card_count_stream(input.as_bytes())] {
+            match result {
+                Ok(count) => {
+                    prop_assert!(fits_table);
+                    prop_assert_eq!(count, copies.iter().sum::<u64>());
+                }
+                Err(_) => prop_assert!(!fits_table),
+            }
+        }
+    }
+}
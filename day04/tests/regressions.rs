@@ -0,0 +1,32 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use aoc_common::{PuzzleError, Result};
+use day04::{calculate_card_count, calculate_card_count_stream, calculate_points, calculate_points_stream, parse_input};
+
+fn card(id: usize, matches: u64) -> String {
+    let numbers = (1..=matches).map(|number| number.to_string()).collect::<Vec<String>>().join(" ");
+    format!("Card {}: {} | {}", id, numbers, numbers)
+}
+
+/// The points of a card with more than 64 matches used to overflow `u64::pow` and panic.
+#[test]
+fn too_many_matches_fail() -> Result<()> {
+    let input = card(1, 64);
+    assert_eq!(calculate_points(&parse_input(&input)?)?, 1 << 63);
+    let input = card(1, 65);
+    for result in [calculate_points(&parse_input(&input)?), calculate_points_stream(input.as_bytes())] {
+        assert!(matches!(result, Err(PuzzleError::LogicError { .. })));
+    }
+    let input = [card(1, 64), card(2, 64)].join("\n");
+    assert!(matches!(calculate_points(&parse_input(&input)?), Err(PuzzleError::LogicError { .. })));
+    Ok(())
+}
+
+/// Every card winning a copy of all following ones doubles the count, which overflows after 64 cards.
+#[test]
+fn too_many_copies_fail() -> Result<()> {
+    let input = (0..70).map(|index| card(index + 1, 69 - index as u64)).collect::<Vec<String>>().join("\n");
+    assert!(matches!(calculate_card_count(&parse_input(&input)?), Err(PuzzleError::LogicError { .. })));
+    assert!(matches!(calculate_card_count_stream(input.as_bytes()), Err(PuzzleError::LogicError { .. })));
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use day05::{create_almanac, find_lowest_location, find_lowest_location_compression, parse_seeds_part1, parse_seeds_part2};
+
+const INPUT: &str = include_str!("../resources/input.txt");
+
+fn bench_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day05/parse");
+    group.bench_function("create_almanac_part1", |b| b.iter(|| create_almanac(black_box(INPUT), parse_seeds_part1)));
+    group.bench_function("create_almanac_part2", |b| b.iter(|| create_almanac(black_box(INPUT), parse_seeds_part2)));
+    group.finish();
+}
+
+fn bench_solve(c: &mut Criterion) {
+    let seeds_almanac = create_almanac(INPUT, parse_seeds_part1).expect("Bundled input is valid");
+    let ranges_almanac = create_almanac(INPUT, parse_seeds_part2).expect("Bundled input is valid");
+
+    let mut group = c.benchmark_group("day05/solve");
+    group.bench_function("part1/find_lowest_location", |b| b.iter(|| find_lowest_location(black_box(&seeds_almanac))));
+    group.bench_function("part1/find_lowest_location_compression", |b| b.iter(|| find_lowest_location_compression(black_box(&seeds_almanac))));
+    group.bench_function("part2/find_lowest_location_compression", |b| b.iter(|| find_lowest_location_compression(black_box(&ranges_almanac))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse, bench_solve);
+criterion_main!(benches);
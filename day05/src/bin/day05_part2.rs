@@ -1,15 +1,19 @@
 use std::time::Instant;
 
-use day05::{create_almanac, find_lowest_location_compression, parse_seeds_part2};
+use aoc_common::input::read_input;
+use aoc_common::Solution;
+use anyhow::Result;
+use day05::{Day05, create_almanac, find_lowest_location_compression, parse_seeds_part2};
 
-fn main() {
+fn main() -> Result<()> {
     let now = Instant::now();
-    let input: Vec<&str> = include_str!("../../resources/input01.txt").split_terminator("\n\n").collect();
-    let almanac = create_almanac(input, parse_seeds_part2);
-    let res = find_lowest_location_compression(&almanac);
+    let input = read_input(Day05::DAY)?;
+    let almanac = create_almanac(&input, parse_seeds_part2)?;
+    let res = find_lowest_location_compression(&almanac)?;
     println!("Result: {}", res);
     let elapsed = now.elapsed();
     println!("Elapsed: {:?}", elapsed);
+    Ok(())
 }
 
 #[cfg(test)]
@@ -17,11 +21,11 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_input01() {
-
-        let input: Vec<&str> = include_str!("../../resources/test_input01.txt").split_terminator("\n\n").collect();
-        let almanac = create_almanac(input, parse_seeds_part2);
-        let res = find_lowest_location_compression(&almanac);
+    fn test_input01() -> Result<()> {
+        let input = include_str!("../../resources/test_input01.txt");
+        let almanac = create_almanac(input, parse_seeds_part2)?;
+        let res = find_lowest_location_compression(&almanac)?;
         assert_eq!(res, 46);
+        Ok(())
     }
 }
\ No newline at end of file
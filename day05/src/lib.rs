@@ -1,11 +1,13 @@
 use std::collections::Bound::{Excluded, Included};
-use std::collections::{Bound, HashMap};
+use std::collections::Bound;
 use std::ops::RangeBounds;
-use std::str::FromStr;
+use std::sync::LazyLock;
 use regex::Regex;
 use itertools::Itertools;
 use rayon::prelude::*;
 use ranges::{GenericRange, OperationResult, Ranges};
+use aoc_common::error::parse_number;
+use aoc_common::{PuzzleError, Result, Solution};
 
 #[derive(Default, Debug,)]
 pub struct Almanac {
@@ -32,14 +34,14 @@ fn get_location_from_seed(almanac: &Almanac, seed: &u64) -> u64 {
     lookup_mapping(&almanac.humidity_to_location, &humidity)
 }
 
-pub fn find_lowest_location(almanac: &Almanac) -> u64 {
-    almanac.seeds.par_iter().flat_map_iter(|(from, len)| *from..*from+*len).into_par_iter().min_by(|x, y| get_location_from_seed(almanac, x).cmp(&get_location_from_seed(almanac, y))).unwrap()
+pub fn find_lowest_location(almanac: &Almanac) -> Result<u64> {
+    almanac.seeds.par_iter().flat_map_iter(|(from, len)| *from..*from+*len).map(|seed| get_location_from_seed(almanac, &seed)).min().ok_or(PuzzleError::LogicError { msg: "Almanac contains no seeds!" })
 }
 
 fn range_to_start_and_len(range: &GenericRange<u64>) -> Option<(u64, u64)> {
     let start = match range.start_bound() {
         Included(start) => *start,
-        Excluded(start) => *start - 1,
+        Excluded(start) => *start + 1,
         Bound::Unbounded => {
             return None
         }
@@ -93,8 +95,8 @@ fn compress_mapping(mappings: &[(u64, u64, u64)], sources: &[(u64, u64)]) -> Vec
     result
 }
 
-pub fn compress_almanac(almanac: &Almanac) -> Vec<(u64, u64)>{
-    let soil = compress_mapping(&almanac.seeds_to_soil, &almanac.seeds);
+fn compress_seeds(almanac: &Almanac, seeds: &[(u64, u64)]) -> Vec<(u64, u64)> {
+    let soil = compress_mapping(&almanac.seeds_to_soil, seeds);
     let fertilizer = compress_mapping(&almanac.soil_to_fertilizer, &soil);
     let water = compress_mapping(&almanac.fertilizer_to_water, &fertilizer);
     let light = compress_mapping(&almanac.water_to_light, &water);
@@ -102,65 +104,150 @@ pub fn compress_almanac(almanac: &Almanac) -> Vec<(u64, u64)>{
     let humidity = compress_mapping(&almanac.temperature_to_humidity, &temperature);
     compress_mapping(&almanac.humidity_to_location, &humidity)
 }
-pub fn find_lowest_location_compression(almanac: &Almanac) -> u64 {
-    let compressed_mappings = compress_almanac(almanac);
-    compressed_mappings.iter().map(|(start, _)| *start).min().unwrap()
+
+pub fn compress_almanac(almanac: &Almanac) -> Vec<(u64, u64)>{
+    compress_seeds(almanac, &almanac.seeds)
+}
+
+fn lowest_location_compression(almanac: &Almanac, seeds: &[(u64, u64)]) -> Result<u64> {
+    let compressed_mappings = compress_seeds(almanac, seeds);
+    compressed_mappings.iter().map(|(start, _)| *start).min().ok_or(PuzzleError::LogicError { msg: "Almanac contains no seeds!" })
+}
+
+pub fn find_lowest_location_compression(almanac: &Almanac) -> Result<u64> {
+    lowest_location_compression(almanac, &almanac.seeds)
 }
 
 
-fn parse_mapping_lines(mapping_lines: &[& str]) -> HashMap<String, Vec<(u64, u64, u64)>> {
-    let heading_re = Regex::new(r"^(?P<map_name>[\w-]+)\s+map:$").unwrap();
-    let mut result = HashMap::new();
-    for mapping in mapping_lines {
-        let mut lines = mapping.lines();
-        let header = lines.next().unwrap();
+impl Almanac {
+    fn mapping_mut(&mut self, mapping_name: &str) -> Option<&mut Vec<(u64, u64, u64)>> {
+        match mapping_name {
+            "seed-to-soil" => Some(&mut self.seeds_to_soil),
+            "soil-to-fertilizer" => Some(&mut self.soil_to_fertilizer),
+            "fertilizer-to-water" => Some(&mut self.fertilizer_to_water),
+            "water-to-light" => Some(&mut self.water_to_light),
+            "light-to-temperature" => Some(&mut self.light_to_temperature),
+            "temperature-to-humidity" => Some(&mut self.temperature_to_humidity),
+            "humidity-to-location" => Some(&mut self.humidity_to_location),
+            &_ => None
+        }
+    }
+}
+
+static HEADING_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(?P<map_name>[\w-]+)\s+map:$").unwrap());
 
-        let heading_matches = heading_re.captures(header).unwrap();
-        let mapping_name: String = heading_matches["map_name"].to_string();
+fn parse_mapping_lines<'a>(almanac: &mut Almanac, mapping_lines: impl Iterator<Item = (usize, &'a str)>) -> Result<()> {
+    let mut current_mapping = None;
+    for (line_index, line) in mapping_lines {
+        if line.trim().is_empty() {
+            current_mapping = None;
+            continue;
+        }
 
-        let mut line_mappings = Vec::new();
-        for line in lines {
-            let mut line_elems = line.split_ascii_whitespace();
-            let to = u64::from_str(line_elems.next().unwrap()).unwrap();
-            let from = u64::from_str(line_elems.next().unwrap()).unwrap();
-            let len = u64::from_str(line_elems.next().unwrap()).unwrap();
+        if let Some(heading_matches) = HEADING_RE.captures(line) {
+            let mapping_name = heading_matches.name("map_name").map_or(line, |x| x.as_str());
+            current_mapping = Some(almanac.mapping_mut(mapping_name).ok_or_else(|| PuzzleError::parser(line_index, line, mapping_name, "Invalid mapping type!"))?);
+            continue;
+        }
 
-            line_mappings.push((from, to, len));
+        let line_mappings = current_mapping.as_mut().ok_or_else(|| PuzzleError::parser(line_index, line, line, "Expected a `<name> map:` heading!"))?;
+        let mut line_elems = line.split_ascii_whitespace();
+        let mut next_number = || -> Result<u64> {
+            let elem = line_elems.next().ok_or_else(|| PuzzleError::parser(line_index, line, line, "Expected `<destination> <source> <length>`!"))?;
+            parse_number(line_index, line, elem)
+        };
+        let to = next_number()?;
+        let from = next_number()?;
+        let len = next_number()?;
+        if from.checked_add(len).is_none() || to.checked_add(len).is_none() {
+            return Err(PuzzleError::parser(line_index, line, line, "Mapping range exceeds the number range!"));
         }
-        result.insert(mapping_name, line_mappings);
+
+        line_mappings.push((from, to, len));
     }
-    result
+    Ok(())
+}
+
+fn parse_seed_numbers(seed_config: &str) -> Result<Vec<u64>> {
+    let (_, seeds) = seed_config.split_once(':').ok_or_else(|| PuzzleError::parser(0, seed_config, seed_config, "Expected `seeds: <numbers>`!"))?;
+    seeds.split_ascii_whitespace().map(|x| parse_number(0, seed_config, x)).collect()
+}
+
+fn check_seeds(seed_config: &str, seeds: Vec<(u64, u64)>) -> Result<Vec<(u64, u64)>> {
+    if seeds.iter().any(|(start, len)| start.checked_add(*len).is_none()) {
+        return Err(PuzzleError::parser(0, seed_config, seed_config, "Seed range exceeds the number range!"));
+    }
+    Ok(seeds)
 }
 
-pub fn parse_seeds_part1(seed_config: &str) -> Vec<(u64, u64)> {
-    seed_config.split_terminator(':').nth(1).unwrap().split_ascii_whitespace().map(|x| (u64::from_str(x).unwrap(), 1)).sorted().collect()
+fn single_seeds(seed_config: &str, seed_numbers: &[u64]) -> Result<Vec<(u64, u64)>> {
+    check_seeds(seed_config, seed_numbers.iter().map(|x| (*x, 1)).sorted().collect())
 }
 
-pub fn parse_seeds_part2(seed_config: &str) -> Vec<(u64, u64)> {
-    seed_config.split_terminator(':').nth(1).unwrap().split_ascii_whitespace().map(|x| u64::from_str(x).unwrap()).tuples().collect()
+fn seed_ranges(seed_config: &str, seed_numbers: &[u64]) -> Result<Vec<(u64, u64)>> {
+    if !seed_numbers.len().is_multiple_of(2) {
+        return Err(PuzzleError::parser(0, seed_config, seed_config, "Seed ranges need a start and a length!"));
+    }
+    check_seeds(seed_config, seed_numbers.iter().copied().tuples().collect())
 }
 
+pub fn parse_seeds_part1(seed_config: &str) -> Result<Vec<(u64, u64)>> {
+    single_seeds(seed_config, &parse_seed_numbers(seed_config)?)
+}
 
+pub fn parse_seeds_part2(seed_config: &str) -> Result<Vec<(u64, u64)>> {
+    seed_ranges(seed_config, &parse_seed_numbers(seed_config)?)
+}
 
-pub fn create_almanac(input: Vec<&str>, parse_seed_config: fn(&str) -> Vec<(u64, u64)>) -> Almanac{
+pub type SeedParser = fn(&str) -> Result<Vec<(u64, u64)>>;
+
+fn parse_almanac<'a, T>(input: &'a str, parse_seed_config: impl FnOnce(&'a str) -> Result<T>) -> Result<(T, Almanac)> {
     let mut almanac = Almanac::default();
-    let seeds_vec = parse_seed_config(input[0]);
-
-    almanac.seeds = seeds_vec;
-
-    let mappings = parse_mapping_lines(&input[1..]);
-
-    for (mapping_name, mapping) in mappings {
-        match mapping_name.as_str() {
-            "seed-to-soil" => almanac.seeds_to_soil = mapping,
-            "soil-to-fertilizer" => almanac.soil_to_fertilizer = mapping,
-            "fertilizer-to-water" => almanac.fertilizer_to_water = mapping,
-            "water-to-light" => almanac.water_to_light = mapping,
-            "light-to-temperature" => almanac.light_to_temperature = mapping,
-            "temperature-to-humidity" => almanac.temperature_to_humidity = mapping,
-            "humidity-to-location" => almanac.humidity_to_location = mapping,
-            &_ => {eprintln!("Invalid mapping type!")}
-        }
+    let mut lines = input.lines().enumerate();
+    let (_, seed_config) = lines.next().ok_or_else(|| PuzzleError::parser(0, input, input, "Missing seeds!"))?;
+    let seeds = parse_seed_config(seed_config)?;
+
+    parse_mapping_lines(&mut almanac, lines)?;
+    Ok((seeds, almanac))
+}
+
+pub fn create_almanac(input: &str, parse_seed_config: SeedParser) -> Result<Almanac> {
+    let (seeds, almanac) = parse_almanac(input, parse_seed_config)?;
+    Ok(Almanac { seeds, ..almanac })
+}
+
+/// The mappings of an almanac with its seed numbers as listed, part 1 reads them as seeds and part 2 as seed ranges.
+pub struct SeedNumbersAlmanac<'a> {
+    seed_config: &'a str,
+    seed_numbers: Vec<u64>,
+    almanac: Almanac,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input<'a> = SeedNumbersAlmanac<'a>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let ((seed_config, seed_numbers), almanac) =
+            parse_almanac(input, |seed_config| Ok((seed_config, parse_seed_numbers(seed_config)?)))?;
+        Ok(SeedNumbersAlmanac { seed_config, seed_numbers, almanac })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1> {
+        lowest_location_compression(&input.almanac, &single_seeds(input.seed_config, &input.seed_numbers)?)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2> {
+        lowest_location_compression(&input.almanac, &seed_ranges(input.seed_config, &input.seed_numbers)?)
     }
-    almanac
 }
+
+#[cfg(feature = "python")]
+pub mod python;
+
+#[cfg(feature = "wasm")]
+aoc_common::wasm_exports!(Day05);
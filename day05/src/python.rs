@@ -0,0 +1,50 @@
+use pyo3::prelude::*;
+
+use crate::{create_almanac, parse_seeds_part1, parse_seeds_part2, Almanac, Day05};
+
+/// Almanac parsed by `parse_part1` or `parse_part2`.
+#[pyclass(name = "Almanac", frozen)]
+pub struct PyAlmanac(Almanac);
+
+/// Parses the almanac with every seed number being a single seed.
+#[pyfunction]
+fn parse_part1(input: &str) -> PyResult<PyAlmanac> {
+    Ok(PyAlmanac(create_almanac(input, parse_seeds_part1)?))
+}
+
+/// Parses the almanac with the seed numbers being pairs of range start and length.
+#[pyfunction]
+fn parse_part2(input: &str) -> PyResult<PyAlmanac> {
+    Ok(PyAlmanac(create_almanac(input, parse_seeds_part2)?))
+}
+
+/// Location ranges as `(start, length)` tuples, the seed ranges are mapped through every mapping.
+#[pyfunction]
+fn compress_almanac(almanac: PyRef<'_, PyAlmanac>) -> Vec<(u64, u64)> {
+    crate::compress_almanac(&almanac.0)
+}
+
+#[pyfunction]
+fn find_lowest_location(almanac: PyRef<'_, PyAlmanac>) -> PyResult<u64> {
+    Ok(crate::find_lowest_location(&almanac.0)?)
+}
+
+#[pyfunction]
+fn find_lowest_location_compression(almanac: PyRef<'_, PyAlmanac>) -> PyResult<u64> {
+    Ok(crate::find_lowest_location_compression(&almanac.0)?)
+}
+
+aoc_common::python_parts!(Day05);
+
+#[pymodule]
+pub fn day05(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyAlmanac>()?;
+    m.add_function(wrap_pyfunction!(parse_part1, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_part2, m)?)?;
+    m.add_function(wrap_pyfunction!(compress_almanac, m)?)?;
+    m.add_function(wrap_pyfunction!(find_lowest_location, m)?)?;
+    m.add_function(wrap_pyfunction!(find_lowest_location_compression, m)?)?;
+    m.add_function(wrap_pyfunction!(part1, m)?)?;
+    m.add_function(wrap_pyfunction!(part2, m)?)?;
+    Ok(())
+}
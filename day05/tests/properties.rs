@@ -0,0 +1,59 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use day05::{create_almanac, find_lowest_location, find_lowest_location_compression, parse_seeds_part1, parse_seeds_part2};
+use proptest::prelude::*;
+
+const MAPPING_NAMES: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// Mapping lines `(destination, source, length)` with non-overlapping source ranges.
+fn mapping() -> impl Strategy<Value = Vec<(u64, u64, u64)>> {
+    prop::collection::vec((0..20_u64, 1..20_u64, 0..200_u64), 0..5).prop_map(|ranges| {
+        let mut next_start = 0;
+        ranges
+            .into_iter()
+            .map(|(gap, len, to)| {
+                let from = next_start + gap;
+                next_start = from + len;
+                (to, from, len)
+            })
+            .collect()
+    })
+}
+
+fn almanac_input() -> impl Strategy<Value = String> {
+    let seed_ranges = prop::collection::vec((0..150_u64, 1..30_u64), 1..4);
+    let mappings = prop::collection::vec(mapping(), MAPPING_NAMES.len());
+    (seed_ranges, mappings).prop_map(|(seed_ranges, mappings)| {
+        let seeds = seed_ranges.iter().map(|(start, len)| format!("{} {}", start, len)).collect::<Vec<_>>().join(" ");
+        let mut input = format!("seeds: {}\n", seeds);
+        for (name, mapping) in MAPPING_NAMES.iter().zip(mappings) {
+            input.push_str(&format!("\n{} map:\n", name));
+            for (to, from, len) in mapping {
+                input.push_str(&format!("{} {} {}\n", to, from, len));
+            }
+        }
+        input
+    })
+}
+
+proptest! {
+    #[test]
+    fn solvers_agree_on_single_seeds(input in almanac_input()) {
+        let almanac = create_almanac(&input, parse_seeds_part1)?;
+        prop_assert_eq!(find_lowest_location(&almanac)?, find_lowest_location_compression(&almanac)?);
+    }
+
+    #[test]
+    fn solvers_agree_on_seed_ranges(input in almanac_input()) {
+        let almanac = create_almanac(&input, parse_seeds_part2)?;
+        prop_assert_eq!(find_lowest_location(&almanac)?, find_lowest_location_compression(&almanac)?);
+    }
+}
@@ -0,0 +1,14 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use aoc_common::{PuzzleError, Result, Solution};
+use day05::Day05;
+
+/// Both views of the seeds used to be parsed up front, so an odd number of seeds failed part 1 as well.
+#[test]
+fn odd_seed_count_only_fails_part2() -> Result<()> {
+    let input = include_str!("../resources/test_input01.txt").replacen("seeds: 79 14 55 13", "seeds: 79 14 55", 1);
+    let almanac = Day05::parse(&input)?;
+    assert_eq!(Day05::part1(&almanac)?, 43);
+    assert!(matches!(Day05::part2(&almanac), Err(PuzzleError::ParserError { line: 1, .. })));
+    Ok(())
+}
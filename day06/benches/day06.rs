@@ -0,0 +1,27 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use day06::{get_winning_race_strategy_count, parse_input, parse_input_kerned};
+
+const INPUT: &str = include_str!("../resources/input.txt");
+
+fn bench_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day06/parse");
+    group.bench_function("parse_input", |b| b.iter(|| parse_input(black_box(INPUT))));
+    group.bench_function("parse_input_kerned", |b| b.iter(|| parse_input_kerned(black_box(INPUT))));
+    group.finish();
+}
+
+fn bench_solve(c: &mut Criterion) {
+    let races = parse_input(INPUT).expect("Bundled input is valid");
+    let kerned_races = parse_input_kerned(INPUT).expect("Bundled input is valid");
+
+    let mut group = c.benchmark_group("day06/solve");
+    group.sample_size(10);
+    group.bench_function("part1", |b| b.iter(|| get_winning_race_strategy_count(black_box(&races))));
+    group.bench_function("part2", |b| b.iter(|| get_winning_race_strategy_count(black_box(&kerned_races))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse, bench_solve);
+criterion_main!(benches);
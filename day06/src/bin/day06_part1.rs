@@ -1,14 +1,18 @@
 use std::time::Instant;
-use day06::{parse_input, get_winning_race_strategy_count};
+use aoc_common::input::read_input;
+use aoc_common::Solution;
+use anyhow::Result;
+use day06::{Day06, get_winning_race_strategy_count, parse_input};
 
-fn main() {
-    let input = include_str!("../../resources/input_part1.txt");
+fn main() -> Result<()> {
+    let input = read_input(Day06::DAY)?;
     let now = Instant::now();
-    let races = parse_input(input);
-    let result = get_winning_race_strategy_count(&races);
+    let races = parse_input(&input)?;
+    let result = get_winning_race_strategy_count(&races)?;
     let elapsed = now.elapsed();
 
     println!("Solution: {}; Elapsed: {:?}", result, elapsed);
+    Ok(())
 }
 
 #[cfg(test)]
@@ -16,11 +20,12 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_input() {
+    fn test_input() -> Result<()> {
         let input = include_str!("../../resources/test_input01.txt");
-        let races = parse_input(input);
+        let races = parse_input(input)?;
         println!("Races: {:?}", races);
-        let result = get_winning_race_strategy_count(&races);
+        let result = get_winning_race_strategy_count(&races)?;
         assert_eq!(288, result);
+        Ok(())
     }
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
-use std::str::FromStr;
 use std::time::Duration;
 use rayon::prelude::*;
+use aoc_common::error::parse_number;
+use aoc_common::{PuzzleError, Result, Solution};
 
 #[derive(Default, Debug)]
 pub struct Race {
@@ -8,25 +9,78 @@ pub struct Race {
     distance_mm: u64
 }
 
-pub fn parse_input(input: &str) -> Vec<Race> {
-    let lines: Vec<&str> = input.lines().collect();
-    let times_iter = lines[0].split_terminator(':').nth(1).unwrap().split_whitespace().map(|x| u64::from_str(x).unwrap());
-    let distances_iter = lines[1].split_terminator(':').nth(1).unwrap().split_whitespace().map(|x| u64::from_str(x).unwrap());
-    times_iter.zip(distances_iter).map(|(time, distance)| Race {duration: Duration::from_millis(time), distance_mm: distance}).collect()
+fn numbers_line<'a>(lines: &[&'a str], line_index: usize, missing: &'static str) -> Result<(&'a str, &'a str)> {
+    let line = *lines.get(line_index).ok_or_else(|| PuzzleError::parser(line_index, "", "", missing))?;
+    let (_, numbers) = line.split_once(':').ok_or_else(|| PuzzleError::parser(line_index, line, line, "Expected `<name>: <numbers>`!"))?;
+    Ok((line, numbers))
 }
 
-fn get_race_distance_for_push_duration_mm(duration: &Duration, push_duration: &Duration) -> u64 {
+fn parse_numbers(line_index: usize, line: &str, numbers: &str) -> Result<Vec<u64>> {
+    numbers.split_whitespace().map(|x| parse_number(line_index, line, x)).collect()
+}
+
+/// Parses the numbers as a single one with the spaces between them removed, errors still point into `line`.
+fn parse_kerned_number(line_index: usize, line: &str, numbers: &str) -> Result<Vec<u64>> {
+    let digits = numbers.trim();
+    if digits.is_empty() {
+        return Ok(Vec::new());
+    }
+    if let Some(text) = digits.split_whitespace().find(|x| !x.bytes().all(|byte| byte.is_ascii_digit())) {
+        return Err(PuzzleError::parser(line_index, line, text, "Invalid number!"));
+    }
+    let kerned: String = digits.split_whitespace().collect();
+    let number = kerned.parse().map_err(|_| PuzzleError::parser(line_index, line, digits, "Invalid number!"))?;
+    Ok(vec![number])
+}
+
+type NumbersParser = fn(usize, &str, &str) -> Result<Vec<u64>>;
+
+fn parse_races(lines: &[&str], parse: NumbersParser) -> Result<Vec<Race>> {
+    let (times_line, times) = numbers_line(lines, 0, "Input is missing the race times!")?;
+    let times = parse(0, times_line, times)?;
+    let (distances_line, distances) = numbers_line(lines, 1, "Input is missing the race distances!")?;
+    let distances = parse(1, distances_line, distances)?;
+    if times.len() != distances.len() {
+        return Err(PuzzleError::parser(1, distances_line, distances_line, "Expected a distance for every race time!"));
+    }
+    Ok(times.into_iter().zip(distances).map(|(time, distance)| Race {duration: Duration::from_millis(time), distance_mm: distance}).collect())
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Race>> {
+    parse_races(&input.lines().collect::<Vec<&str>>(), parse_numbers)
+}
+
+pub fn parse_input_kerned(input: &str) -> Result<Vec<Race>> {
+    parse_races(&input.lines().collect::<Vec<&str>>(), parse_kerned_number)
+}
+
+/// The races of part 1 with the lines they were read from, part 2 reads the lines again as a single kerned race.
+pub struct RaceSheet<'a> {
+    lines: Vec<&'a str>,
+    races: Vec<Race>,
+}
+
+const DISTANCE_OVERFLOW: PuzzleError = PuzzleError::LogicError { msg: "Distance does not fit into 64 bits!" };
+const PRODUCT_OVERFLOW: PuzzleError = PuzzleError::LogicError { msg: "Product of the winning strategies does not fit into 64 bits!" };
+
+fn get_race_distance_for_push_duration_mm(duration: &Duration, push_duration: &Duration) -> Result<u64> {
     let race_duration = *duration - *push_duration;
     let speed_mm_ms = push_duration.as_millis() as u64;
 
-    race_duration.as_millis() as u64 * speed_mm_ms
+    (race_duration.as_millis() as u64).checked_mul(speed_mm_ms).ok_or(DISTANCE_OVERFLOW)
 }
 
-pub fn get_winning_race_strategy_count(races: &[Race]) -> u64 {
-    races.par_iter().map(|race| {
-        let possible_push_durations_ms = race.duration.as_millis() as u64;
-        (0..possible_push_durations_ms).into_par_iter().map(|x| get_race_distance_for_push_duration_mm(&race.duration, &Duration::from_millis(x))).filter(|x| *x > race.distance_mm).count() as u64
-    }).product()
+fn get_winning_strategy_count(race: &Race) -> Result<u64> {
+    // The distance is longest when pushing for half the race, if that one fits all the others do as well.
+    get_race_distance_for_push_duration_mm(&race.duration, &(race.duration / 2))?;
+    let possible_push_durations_ms = race.duration.as_millis() as u64;
+    (0..possible_push_durations_ms).into_par_iter()
+        .map(|x| Ok(u64::from(get_race_distance_for_push_duration_mm(&race.duration, &Duration::from_millis(x))? > race.distance_mm)))
+        .try_reduce(|| 0, |a, b| Ok(a + b))
+}
+
+pub fn get_winning_race_strategy_count(races: &[Race]) -> Result<u64> {
+    races.par_iter().map(get_winning_strategy_count).try_reduce(|| 1, |a, b| a.checked_mul(b).ok_or(PRODUCT_OVERFLOW))
 
 /*    for race in races.iter() {
         let possible_push_durations_ms = race.duration.as_millis() as u64;
@@ -34,4 +88,30 @@ pub fn get_winning_race_strategy_count(races: &[Race]) -> u64 {
         result *= winning_strategies;
     }
     result
-*/}
\ No newline at end of file
+*/}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input<'a> = RaceSheet<'a>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let lines: Vec<&str> = input.lines().collect();
+        let races = parse_races(&lines, parse_numbers)?;
+        Ok(RaceSheet { lines, races })
+    }
+
+    fn part1(sheet: &Self::Input<'_>) -> Result<Self::Answer1> {
+        get_winning_race_strategy_count(&sheet.races)
+    }
+
+    fn part2(sheet: &Self::Input<'_>) -> Result<Self::Answer2> {
+        get_winning_race_strategy_count(&parse_races(&sheet.lines, parse_kerned_number)?)
+    }
+}
+
+#[cfg(feature = "wasm")]
+aoc_common::wasm_exports!(Day06);
@@ -0,0 +1,9 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use aoc_common::examples::assert_examples;
+use day06::Day06;
+
+#[test]
+fn examples() {
+    assert_examples::<Day06>(concat!(env!("CARGO_MANIFEST_DIR"), "/resources"));
+}
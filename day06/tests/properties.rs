@@ -0,0 +1,39 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use aoc_common::Solution;
+use day06::{get_winning_race_strategy_count, parse_input, parse_input_kerned, Day06};
+use proptest::prelude::*;
+
+fn winning_strategies(time: u64, distance: u64) -> u64 {
+    (0..=time).filter(|push| push * (time - push) > distance).count() as u64
+}
+
+fn races_with_max_time(max_time: u64, max_races: usize) -> impl Strategy<Value = Vec<(u64, u64)>> {
+    prop::collection::vec((1..max_time).prop_flat_map(|time| (Just(time), 0..time * time / 4 + 2)), 1..max_races)
+}
+
+fn render(races: &[(u64, u64)]) -> String {
+    let times: Vec<String> = races.iter().map(|(time, _)| format!("{:>5}", time)).collect();
+    let distances: Vec<String> = races.iter().map(|(_, distance)| format!("{:>5}", distance)).collect();
+    format!("Time:    {}\nDistance:{}\n", times.join(" "), distances.join(" "))
+}
+
+proptest! {
+    #[test]
+    fn winning_strategies_match_reference(races in races_with_max_time(100, 5)) {
+        let parsed = parse_input(&render(&races))?;
+        let expected: u64 = races.iter().map(|(time, distance)| winning_strategies(*time, *distance)).product();
+        prop_assert_eq!(get_winning_race_strategy_count(&parsed)?, expected);
+    }
+
+    #[test]
+    fn kerning_concatenates_the_numbers(races in races_with_max_time(50, 3)) {
+        let input = render(&races);
+        let parsed = parse_input_kerned(&input)?;
+        let concat = |numbers: Vec<u64>| numbers.iter().map(u64::to_string).collect::<String>().parse::<u64>().unwrap();
+        let time = concat(races.iter().map(|(time, _)| *time).collect());
+        let distance = concat(races.iter().map(|(_, distance)| *distance).collect());
+        prop_assert_eq!(get_winning_race_strategy_count(&parsed)?, winning_strategies(time, distance));
+        prop_assert_eq!(Day06::part2(&Day06::parse(&input)?)?, winning_strategies(time, distance));
+    }
+}
@@ -0,0 +1,67 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use aoc_common::{PuzzleError, Result, Solution};
+use day06::{get_winning_race_strategy_count, parse_input, parse_input_kerned, Day06};
+
+fn position(error: PuzzleError) -> (usize, usize, String) {
+    match error {
+        PuzzleError::ParserError { line, column, text, .. } => (line, column, text),
+        error => panic!("Expected a parser error, got {}", error),
+    }
+}
+
+/// A missing line used to be reported as a logic error without any position.
+#[test]
+fn missing_line_is_a_parser_error() {
+    for parse in [parse_input, parse_input_kerned] {
+        assert_eq!(position(parse("").err().unwrap()).0, 1);
+        assert_eq!(position(parse("Time: 7 15\n").err().unwrap()).0, 2);
+    }
+}
+
+/// The kerned input used to be parsed with its spaces removed, so columns pointed into the kerned text.
+#[test]
+fn kerned_errors_point_into_the_original_line() {
+    let input = "Time:      7  15   30\nDistance:  9  4x   200\n";
+    assert_eq!(position(parse_input_kerned(input).err().unwrap()), (2, 15, "4x".to_string()));
+    assert_eq!(position(parse_input(input).err().unwrap()), (2, 15, "4x".to_string()));
+    let input = "Time:  99999 99999 99999 99999\nDistance:  9\n";
+    assert_eq!(position(parse_input_kerned(input).err().unwrap()), (1, 8, "99999 99999 99999 99999".to_string()));
+}
+
+/// Both parts used to be parsed up front, so numbers too long to kern failed part 1 as well.
+#[test]
+fn kerning_overflow_only_fails_part2() -> Result<()> {
+    let input = format!("Time:    {}\nDistance:{}\n", "  7  15   30".repeat(7), "  9  40  200".repeat(7));
+    let races = Day06::parse(&input)?;
+    assert_eq!(Day06::part1(&races)?, 288_u64.pow(7));
+    assert!(matches!(Day06::part2(&races), Err(PuzzleError::ParserError { line: 1, .. })));
+    Ok(())
+}
+
+/// Part 2 used to join the parsed numbers, dropping the leading zeros the kerned text keeps.
+#[test]
+fn kerning_keeps_leading_zeros() -> Result<()> {
+    let input = "Time: 7 05\nDistance: 9 040\n";
+    let kerned = get_winning_race_strategy_count(&parse_input_kerned(input)?)?;
+    assert_eq!(kerned, 678);
+    assert_eq!(Day06::part2(&Day06::parse(input)?)?, kerned);
+    Ok(())
+}
+
+/// The distances used to overflow in races longer than 2^33 milliseconds.
+#[test]
+fn overflowing_distance_fails() -> Result<()> {
+    let races = Day06::parse("Time: 8589934592\nDistance: 0\n")?;
+    assert!(matches!(Day06::part1(&races), Err(PuzzleError::LogicError { .. })));
+    Ok(())
+}
+
+/// The product of the winning strategies used to overflow with enough races.
+#[test]
+fn overflowing_product_fails() -> Result<()> {
+    let input = format!("Time:{}\nDistance:{}\n", " 4".repeat(41), " 0".repeat(41));
+    let races = Day06::parse(&input)?;
+    assert!(matches!(Day06::part1(&races), Err(PuzzleError::LogicError { .. })));
+    Ok(())
+}
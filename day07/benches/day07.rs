@@ -0,0 +1,26 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use day07::{calculate_result, parse_input, parse_input_with_jokers};
+
+const INPUT: &str = include_str!("../resources/input.txt");
+
+fn bench_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day07/parse");
+    group.bench_function("parse_input", |b| b.iter(|| parse_input(black_box(INPUT))));
+    group.bench_function("parse_input_with_jokers", |b| b.iter(|| parse_input_with_jokers(black_box(INPUT))));
+    group.finish();
+}
+
+fn bench_solve(c: &mut Criterion) {
+    let hands = parse_input(INPUT).expect("Bundled input is valid");
+    let joker_hands = parse_input_with_jokers(INPUT).expect("Bundled input is valid");
+
+    let mut group = c.benchmark_group("day07/solve");
+    group.bench_function("part1", |b| b.iter(|| calculate_result(black_box(&hands))));
+    group.bench_function("part2", |b| b.iter(|| calculate_result(black_box(&joker_hands))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse, bench_solve);
+criterion_main!(benches);
@@ -1,13 +1,17 @@
 use std::time::Instant;
-use day07::{calculate_result, parse_input};
+use aoc_common::input::read_input;
+use aoc_common::Solution;
+use anyhow::Result;
+use day07::{Day07, calculate_result, parse_input};
 
-fn main() {
-    let input = include_str!("../../resources/input.txt");
+fn main() -> Result<()> {
+    let input = read_input(Day07::DAY)?;
     let now = Instant::now();
-    let mut hands = parse_input(input);
-    let result = calculate_result(&mut hands);
+    let hands = parse_input(&input)?;
+    let result = calculate_result(&hands)?;
     let elapsed = now.elapsed();
     println!("Result: {}, Elapsed: {:?}", result, elapsed);
+    Ok(())
 }
 
 #[cfg(test)]
@@ -15,12 +19,12 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_input() {
-
+    fn test_input() -> Result<()> {
         let input = include_str!("../../resources/test_input.txt");
-        let mut hands = parse_input(input);
+        let hands = parse_input(input)?;
         println!("Hands: {:?}", hands);
-        let result = calculate_result(&mut hands);
+        let result = calculate_result(&hands)?;
         assert_eq!(6440, result);
+        Ok(())
     }
 }
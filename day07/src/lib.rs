@@ -1,31 +1,36 @@
 use std::cmp::Ordering;
-use std::cmp::Ordering::{Equal, Greater, Less};
-use std::collections::HashMap;
+use std::cmp::Ordering::Equal;
+use std::collections::{BTreeMap, HashMap};
 use std::convert::TryFrom;
-use std::str::FromStr;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use aoc_common::error::parse_number;
+use aoc_common::stream::for_each_line;
+use aoc_common::{PuzzleError, Result, Solution, StreamingSolution};
 use crate::HandType::{FiveOfAKind, FourOfAKind, FullHouse, HighCard, OnePair, ThreeOfAKind, TwoPair};
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 enum Card {
-    A,
-    K,
-    Q,
-    J,
-    T,
-    Nine,
-    Eight,
-    Seven,
-    Six,
-    Five,
-    Four,
-    Three,
+    Joker,
     Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    T,
+    J,
+    Q,
+    K,
+    A,
 }
 
 impl TryFrom<char> for Card {
     type Error = ();
 
-    fn try_from(value: char) -> Result<Self, Self::Error> {
+    fn try_from(value: char) -> std::result::Result<Self, Self::Error> {
         match value {
             'A' => Ok(Card::A),
             'K' => Ok(Card::K),
@@ -47,229 +52,112 @@ impl TryFrom<char> for Card {
     }
 }
 
-impl PartialOrd<Self> for Card {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        match self {
-            Card::A => {
-                match other {
-                    Card::A => {Some(Equal)}
-                    Card::K | Card::Q | Card::J | Card::T | Card::Nine | Card::Eight | Card::Seven | Card::Six | Card::Five | Card::Four | Card::Three | Card::Two => {Some(Greater)}
-                }
-            },
-            Card::K => {
-                match other {
-                    Card::A => {Some(Less)},
-                    Card::K => {Some(Equal)},
-                    Card::Q | Card::J | Card::T | Card::Nine | Card::Eight | Card::Seven | Card::Six | Card::Five | Card::Four | Card::Three | Card::Two => {Some(Greater)}
-                }
-            }
-            Card::Q => {
-                match other {
-                    Card::A | Card::K => {Some(Less)},
-                    Card::Q => {Some(Equal)},
-                    Card::J | Card::T | Card::Nine | Card::Eight | Card::Seven | Card::Six | Card::Five | Card::Four | Card::Three | Card::Two => {Some(Greater)}
-                }
-            }
-            Card::J => {
-                match other {
-                    Card::A | Card::K | Card::Q => {Some(Less)},
-                    Card::J => {Some(Equal)},
-                    Card::T | Card::Nine | Card::Eight | Card::Seven | Card::Six | Card::Five | Card::Four | Card::Three | Card::Two => {Some(Greater)}
-                }
-            }
-            Card::T => {
-                match other {
-                    Card::A | Card::K | Card::Q | Card::J => {Some(Less)},
-                    Card::T => {Some(Equal)},
-                    Card::Nine | Card::Eight | Card::Seven | Card::Six | Card::Five | Card::Four | Card::Three | Card::Two => {Some(Greater)}
-                }
-            }
-            Card::Nine => {
-                match other {
-                    Card::A | Card::K | Card::Q | Card::J | Card::T => {Some(Less)},
-                    Card::Nine => {Some(Equal)},
-                    Card::Eight | Card::Seven | Card::Six | Card::Five | Card::Four | Card::Three | Card::Two => {Some(Greater)}
-                }
-            }
-            Card::Eight => {
-                match other {
-                    Card::A | Card::K | Card::Q | Card::J | Card::T | Card::Nine=> {Some(Less)},
-                    Card::Eight => {Some(Equal)},
-                    Card::Seven | Card::Six | Card::Five | Card::Four | Card::Three | Card::Two => {Some(Greater)}
-                }
-            }
-            Card::Seven => {
-                match other {
-                    Card::A | Card::K | Card::Q | Card::J | Card::T | Card::Nine | Card::Eight => {Some(Less)},
-                    Card::Seven => {Some(Equal)},
-                    Card::Six | Card::Five | Card::Four | Card::Three | Card::Two => {Some(Greater)}
-                }
-            }
-            Card::Six => {
-                match other {
-                    Card::A | Card::K | Card::Q | Card::J | Card::T | Card::Nine | Card::Eight | Card::Seven => {Some(Less)},
-                    Card::Six => {Some(Equal)},
-                    Card::Five | Card::Four | Card::Three | Card::Two => {Some(Greater)}
-                }
-            }
-            Card::Five => {
-                match other {
-                    Card::A | Card::K | Card::Q | Card::J | Card::T | Card::Nine | Card::Eight | Card::Seven | Card::Six => {Some(Less)},
-                    Card::Five => {Some(Equal)},
-                    Card::Four | Card::Three | Card::Two => {Some(Greater)}
-                }
-            }
-            Card::Four => {
-                match other {
-                    Card::A | Card::K | Card::Q | Card::J | Card::T | Card::Nine | Card::Eight | Card::Seven | Card::Six | Card::Five => {Some(Less)},
-                    Card::Four => {Some(Equal)},
-                    Card::Three | Card::Two => {Some(Greater)}
-                }
-            }
-            Card::Three => {
-                match other {
-                    Card::A | Card::K | Card::Q | Card::J | Card::T | Card::Nine | Card::Eight | Card::Seven | Card::Six | Card::Five | Card::Four => {Some(Less)},
-                    Card::Three => {Some(Equal)},
-                    Card::Two => {Some(Greater)}
-                }
-            }
-            Card::Two => {
-                match other {
-                    Card::A | Card::K | Card::Q | Card::J | Card::T | Card::Nine | Card::Eight | Card::Seven | Card::Six | Card::Five | Card::Four | Card::Three => {Some(Less)},
-                    Card::Two => {Some(Equal)},
-                }
-            }
-        }
-    }
-}
-
-impl Ord for Card {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+impl Display for Card {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Card::A => 'A',
+            Card::K => 'K',
+            Card::Q => 'Q',
+            Card::J | Card::Joker => 'J',
+            Card::T => 'T',
+            Card::Nine => '9',
+            Card::Eight => '8',
+            Card::Seven => '7',
+            Card::Six => '6',
+            Card::Five => '5',
+            Card::Four => '4',
+            Card::Three => '3',
+            Card::Two => '2',
+        })
     }
 }
 
 impl Card {
-    fn from_str(input: &str) -> Vec<Self> {
-        input.chars().map(|x| Card::try_from(x).unwrap()).collect()
+    fn parse_hand(line_index: usize, line: &str, hand: &str, with_jokers: bool) -> Result<Vec<Self>> {
+        hand.char_indices().map(|(index, x)| {
+            let card = Card::try_from(x).map_err(|_| PuzzleError::parser(line_index, line, &hand[index..index + x.len_utf8()], "Invalid card!"))?;
+            Ok(match card {
+                Card::J if with_jokers => Card::Joker,
+                _ => card
+            })
+        }).collect()
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub struct Hand {
     cards: Vec<Card>,
     hand_type: HandType,
     bid: u64
 }
 
+impl Display for Hand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for card in self.cards.iter() {
+            write!(f, "{}", card)?;
+        }
+        write!(f, " {}", self.bid)
+    }
+}
+
 impl PartialOrd<Self> for Hand {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        let hand_order = self.hand_type.partial_cmp(&other.hand_type);
-        if let Some(Equal) = hand_order {
-            Some(self.cards.cmp(&other.cards))
-        } else {
-            hand_order
-        }
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for Hand {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+        let hand_order = self.hand_type.cmp(&other.hand_type);
+        if let Equal = hand_order {
+            self.cards.cmp(&other.cards)
+        } else {
+            hand_order
+        }
     }
 }
 
 
-#[derive(PartialEq, Eq, Debug)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
 enum HandType {
-    FiveOfAKind,
-    FourOfAKind,
-    FullHouse,
-    ThreeOfAKind,
-    TwoPair,
+    HighCard,
     OnePair,
-    HighCard
-}
-
-impl PartialOrd<Self> for HandType {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        match self {
-            FiveOfAKind => {
-                match other {
-                    FiveOfAKind => {Some(Equal)}
-                    FourOfAKind | FullHouse | ThreeOfAKind | TwoPair | OnePair | HighCard => {Some(Greater)}
-                }
-            }
-            FourOfAKind => {
-                match other {
-                    FiveOfAKind => {Some(Less)},
-                    FourOfAKind => {Some(Equal)},
-                    FullHouse | ThreeOfAKind | TwoPair | OnePair | HighCard => {Some(Greater)}
-                }
-            }
-            FullHouse => {
-                match other {
-                    FiveOfAKind| FourOfAKind => {Some(Less)},
-                    FullHouse => {Some(Equal)},
-                    ThreeOfAKind | TwoPair | OnePair | HighCard => {Some(Greater)}
-                }
-            }
-            ThreeOfAKind => {
-                match other {
-                    FiveOfAKind| FourOfAKind | FullHouse => {Some(Less)},
-                    ThreeOfAKind => {Some(Equal)},
-                    TwoPair | OnePair | HighCard => {Some(Greater)}
-                }
-            }
-            TwoPair => {
-                match other {
-                    FiveOfAKind| FourOfAKind | FullHouse | ThreeOfAKind => {Some(Less)},
-                    TwoPair => {Some(Equal)},
-                    OnePair | HighCard => {Some(Greater)}
-                }
-            }
-            OnePair => {
-                match other {
-                    FiveOfAKind| FourOfAKind | FullHouse | ThreeOfAKind | TwoPair => {Some(Less)},
-                    OnePair => {Some(Equal)},
-                    HighCard => {Some(Greater)}
-                }
-            }
-            HighCard => {
-                match other {
-                    FiveOfAKind| FourOfAKind | FullHouse | ThreeOfAKind | TwoPair | OnePair => {Some(Less)},
-                    HighCard => {Some(Equal)},
-                }
-            }
-        }
-    }
-}
-
-impl Ord for HandType {
-    fn cmp(&self, other: &Self) -> Ordering {
-       self.partial_cmp(other).unwrap()
-    }
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
 }
 
 impl HandType {
-    fn from_cards(cards: &Vec<Card>) -> Self {
+    fn from_cards(cards: &[Card]) -> Self {
         let mut cards_set: HashMap<Card, u64> = HashMap::new();
         for card in cards {
             *cards_set.entry(*card).or_insert(0) +=1;
         }
 
-        let highest_number_of_eq_cards = cards_set.iter().max_by(|(_, x), (_, y)| x.cmp(y)).unwrap();
+        let joker_count: u64 = cards_set.get(&Card::Joker).copied().unwrap_or(0);
+        let highest_number_of_eq_cards = cards_set
+            .iter()
+            .filter(|(card, _)| (*card).ne(&Card::Joker))
+            .max_by(|(_, x), (_, y)| x.cmp(y))
+            .unwrap_or((&Card::Joker, &0_u64));
 
-        if *highest_number_of_eq_cards.1 == 5 {
+        if u64::saturating_sub(5, *highest_number_of_eq_cards.1) <= joker_count {
             return FiveOfAKind;
         }
 
-        if *highest_number_of_eq_cards.1 == 4 {
+        if u64::saturating_sub(4, *highest_number_of_eq_cards.1) <= joker_count {
             return FourOfAKind;
         }
 
-        if *highest_number_of_eq_cards.1 == 3 {
-            let two_similar = cards_set.iter().find(|(_, x)| **x == 2);
+        if u64::saturating_sub(3, *highest_number_of_eq_cards.1) <= joker_count {
+            let available_jokers = u64::saturating_sub(joker_count, u64::saturating_sub(3, *highest_number_of_eq_cards.1));
+            let two_similar = cards_set
+                .iter()
+                .filter(|(x, _)| Card::Joker.ne(x))
+                .filter(|(x, _)| x.ne(&highest_number_of_eq_cards.0))
+                .find(|(_, x)| u64::saturating_sub(2, **x) <= available_jokers);
 
             return if two_similar.is_some() {
                 FullHouse
@@ -278,10 +166,15 @@ impl HandType {
             }
         }
 
-        if *highest_number_of_eq_cards.1 == 2 {
-
-            let two_similar_count = cards_set.iter().filter(|(_, x)| **x == 2).count();
-            return if two_similar_count == 2 {
+        if u64::saturating_sub(2, *highest_number_of_eq_cards.1) <= joker_count {
+            let available_jokers = u64::saturating_sub(joker_count, u64::saturating_sub(2, *highest_number_of_eq_cards.1));
+            let other_similar_count = cards_set
+                .iter()
+                .filter(|(x, _)| Card::Joker.ne(x))
+                .filter(|(x, _)| x.ne(&highest_number_of_eq_cards.0))
+                .filter(|(_, x)| u64::saturating_sub(2, **x) <= available_jokers)
+                .count();
+            return if other_similar_count > 0 {
                 TwoPair
             } else {
                 OnePair
@@ -292,25 +185,232 @@ impl HandType {
     }
 }
 
-pub fn parse_input(input: &str) -> Vec<Hand> {
-    let mut hands = Vec::new();
+fn parse_hand_line(line_index: usize, line: &str, with_jokers: bool) -> Result<Hand> {
+    let mut line_iterator = line.split_whitespace();
+    let (Some(hand_line), Some(bid_text)) = (line_iterator.next(), line_iterator.next()) else {
+        return Err(PuzzleError::parser(line_index, line, line, "Expected `<cards> <bid>`!"));
+    };
+    let cards = Card::parse_hand(line_index, line, hand_line, with_jokers)?;
+    if cards.len() != 5 {
+        return Err(PuzzleError::parser(line_index, line, hand_line, "A hand needs five cards!"));
+    }
 
-    for line in input.lines() {
-        let mut line_iterator = line.split_whitespace();
-        let hand_line = line_iterator.next().unwrap();
-        let cards = Card::from_str(hand_line);
+    let hand_type = HandType::from_cards(&cards);
+    let bid = parse_number(line_index, line, bid_text)?;
+    Ok(Hand {cards, bid, hand_type})
+}
 
-        let hand_type = HandType::from_cards(&cards);
-        let bid = u64::from_str(line_iterator.next().unwrap()).unwrap();
-        hands.push(Hand {cards, bid, hand_type})
-    }
+fn parse_hands(input: &str, with_jokers: bool) -> Result<Vec<Hand>> {
+    input.lines().enumerate().map(|(line_index, line)| parse_hand_line(line_index, line, with_jokers)).collect()
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Hand>> {
+    parse_hands(input, false)
+}
+
+pub fn parse_input_with_jokers(input: &str) -> Result<Vec<Hand>> {
+    parse_hands(input, true)
+}
 
+/// The hands with every `J` read as a joker, as [`parse_input_with_jokers`] reads them.
+pub fn with_jokers(hands: &[Hand]) -> Vec<Hand> {
     hands
+        .iter()
+        .map(|hand| {
+            let cards: Vec<Card> = hand.cards.iter().map(|card| if *card == Card::J { Card::Joker } else { *card }).collect();
+            Hand { hand_type: HandType::from_cards(&cards), cards, bid: hand.bid }
+        })
+        .collect()
 }
 
-pub fn calculate_result(hands: &mut Vec<Hand>) -> u64 {
+const OVERFLOW: PuzzleError = PuzzleError::LogicError { msg: "Winnings do not fit into 64 bits!" };
+
+fn checked(value: Option<u64>) -> Result<u64> {
+    value.ok_or(OVERFLOW)
+}
+
+pub fn calculate_result(hands: &[Hand]) -> Result<u64> {
+    let mut hands: Vec<&Hand> = hands.iter().collect();
     hands.sort();
     let ranks = 1..=hands.len()as u64;
-    hands.iter().map(|hand| hand.bid).zip(ranks).map(|(bid, rank)| bid * rank).sum()
+    hands.iter().map(|hand| hand.bid).zip(ranks).try_fold(0_u64, |sum, (bid, rank)| checked(sum.checked_add(checked(bid.checked_mul(rank))?)))
+}
 
-}
\ No newline at end of file
+#[derive(Default)]
+struct EqualHands {
+    count: u64,
+    bids: u64,
+    /// Bids multiplied by the rank among the equal hands, which are ranked in the order they were read.
+    ranked_bids: u64,
+}
+
+fn calculate_result_stream_with<R: BufRead>(reader: R, with_jokers: bool) -> Result<u64> {
+    // There are only 13^5 different hands, so keeping the bids per hand needs bounded memory for any number of hands.
+    let mut hands: BTreeMap<(HandType, Vec<Card>), EqualHands> = BTreeMap::new();
+    for_each_line(reader, |line_index, line| {
+        let hand = parse_hand_line(line_index, line, with_jokers)?;
+        let equal_hands = hands.entry((hand.hand_type, hand.cards)).or_default();
+        equal_hands.count += 1;
+        equal_hands.bids = checked(equal_hands.bids.checked_add(hand.bid))?;
+        let ranked_bid = checked(equal_hands.count.checked_mul(hand.bid))?;
+        equal_hands.ranked_bids = checked(equal_hands.ranked_bids.checked_add(ranked_bid))?;
+        Ok(())
+    })?;
+
+    let mut weaker_hands = 0_u64;
+    let mut result = 0_u64;
+    for equal_hands in hands.values() {
+        let weaker_bids = checked(weaker_hands.checked_mul(equal_hands.bids))?;
+        result = checked(result.checked_add(weaker_bids).and_then(|result| result.checked_add(equal_hands.ranked_bids)))?;
+        weaker_hands += equal_hands.count;
+    }
+    Ok(result)
+}
+
+pub fn calculate_result_stream<R: BufRead>(reader: R) -> Result<u64> {
+    calculate_result_stream_with(reader, false)
+}
+
+pub fn calculate_result_with_jokers_stream<R: BufRead>(reader: R) -> Result<u64> {
+    calculate_result_stream_with(reader, true)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Input<'a> = Vec<Hand>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(hands: &Self::Input<'_>) -> Result<Self::Answer1> {
+        calculate_result(hands)
+    }
+
+    fn part2(hands: &Self::Input<'_>) -> Result<Self::Answer2> {
+        calculate_result(&with_jokers(hands))
+    }
+}
+
+impl StreamingSolution for Day07 {
+    fn part1_stream(reader: &mut dyn BufRead) -> Result<Self::Answer1> {
+        calculate_result_stream(reader)
+    }
+
+    fn part2_stream(reader: &mut dyn BufRead) -> Result<Self::Answer2> {
+        calculate_result_with_jokers_stream(reader)
+    }
+}
+
+#[cfg(feature = "python")]
+pub mod python;
+
+#[cfg(feature = "wasm")]
+aoc_common::wasm_exports!(Day07);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fullhouse_joker() {
+        let hand = vec![Card::Four, Card::Joker, Card::Three, Card::Four, Card::Three];
+        let result = HandType::from_cards(&hand);
+        assert_eq!(result, FullHouse);
+
+        let hand = vec![Card::Four, Card::Joker, Card::Three, Card::Joker, Card::Three];
+        let result = HandType::from_cards(&hand);
+        assert_eq!(result, FourOfAKind);
+    }
+
+    #[test]
+    fn test_three_of_a_kind_joker() {
+        let hand = vec![Card::Four, Card::Joker, Card::Three, Card::Two, Card::Three];
+        let result = HandType::from_cards(&hand);
+        assert_eq!(result, ThreeOfAKind);
+
+        let hand = vec![Card::Four, Card::Joker, Card::Two, Card::Joker, Card::Three];
+        let result = HandType::from_cards(&hand);
+        assert_eq!(result, ThreeOfAKind);
+    }
+
+    #[test]
+    fn test_fullhouse_no_joker() {
+        let hand = vec![Card::Four, Card::Four, Card::Three, Card::Four, Card::Three];
+        let result = HandType::from_cards(&hand);
+        assert_eq!(result, FullHouse);
+    }
+
+    #[test]
+    fn test_four_of_a_kind_joker() {
+        let mut hand = vec![Card::Four, Card::Four, Card::Joker, Card::Four, Card::Three];
+        let mut result = HandType::from_cards(&hand);
+        assert_eq!(result, FourOfAKind);
+        hand = vec![Card::Four, Card::Four, Card::Joker, Card::Joker, Card::Three];
+        result = HandType::from_cards(&hand);
+        assert_eq!(result, FourOfAKind);
+        hand = vec![Card::Four, Card::Joker, Card::Joker, Card::Joker, Card::Three];
+        result = HandType::from_cards(&hand);
+        assert_eq!(result, FourOfAKind);
+        hand = vec![Card::Joker, Card::Joker, Card::Joker, Card::Joker, Card::Three];
+        result = HandType::from_cards(&hand);
+        assert_eq!(result, FiveOfAKind);
+    }
+
+    #[test]
+    fn test_five_of_a_kind_joker() {
+        let mut hand = vec![Card::Four, Card::Four, Card::Joker, Card::Four, Card::Four];
+        let mut result = HandType::from_cards(&hand);
+        assert_eq!(result, FiveOfAKind);
+        hand = vec![Card::Four, Card::Joker, Card::Joker, Card::Four, Card::Four];
+        result = HandType::from_cards(&hand);
+        assert_eq!(result, FiveOfAKind);
+        hand = vec![Card::Four, Card::Joker, Card::Joker, Card::Joker, Card::Four];
+        result = HandType::from_cards(&hand);
+        assert_eq!(result, FiveOfAKind);
+        hand = vec![Card::Four, Card::Joker, Card::Joker, Card::Joker, Card::Joker];
+        result = HandType::from_cards(&hand);
+        assert_eq!(result, FiveOfAKind);
+        hand = vec![Card::Joker, Card::Joker, Card::Joker, Card::Joker, Card::Joker];
+        result = HandType::from_cards(&hand);
+        assert_eq!(result, FiveOfAKind);
+    }
+
+    #[test]
+    fn test_two_pair_joker() {
+        let mut hand = vec![Card::Four, Card::Three, Card::Two, Card::Joker, Card::Seven];
+        let mut result = HandType::from_cards(&hand);
+        assert_eq!(result, OnePair);
+        hand = vec![Card::Four, Card::Four, Card::Three, Card::Joker, Card::Seven];
+        result = HandType::from_cards(&hand);
+        assert_eq!(result, ThreeOfAKind);
+    }
+
+    #[test]
+    fn test_invalid_card() {
+        let error = parse_input("32T3K 765\nT55X5 684").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Failed to parse input at line 2, column 4: Invalid card! (found `X`)"
+        );
+    }
+
+    #[test]
+    fn test_hand_size() {
+        let error = parse_input("32T3K 765\nT55 684").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Failed to parse input at line 2, column 1: A hand needs five cards! (found `T55`)"
+        );
+    }
+
+    #[test]
+    fn test_joker_is_weakest_card() {
+        assert!(Card::Joker < Card::Two);
+        assert!(Card::J > Card::T);
+    }
+}
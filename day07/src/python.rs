@@ -0,0 +1,49 @@
+use pyo3::prelude::*;
+
+use crate::{Day07, Hand};
+
+/// Hand of Camel Cards with its bid, printed as in the input.
+#[pyclass(name = "Hand", frozen)]
+pub struct PyHand(Hand);
+
+#[pymethods]
+impl PyHand {
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Hand('{}')", self.0)
+    }
+}
+
+#[pyfunction]
+fn parse_input(input: &str) -> PyResult<Vec<PyHand>> {
+    Ok(crate::parse_input(input)?.into_iter().map(PyHand).collect())
+}
+
+/// Parses the hands with `J` being a joker instead of a jack.
+#[pyfunction]
+fn parse_input_with_jokers(input: &str) -> PyResult<Vec<PyHand>> {
+    Ok(crate::parse_input_with_jokers(input)?.into_iter().map(PyHand).collect())
+}
+
+/// Total winnings of the hands, the sum of every bid multiplied with the rank of its hand.
+#[pyfunction]
+fn calculate_result(hands: Vec<PyRef<'_, PyHand>>) -> PyResult<u64> {
+    let hands: Vec<Hand> = hands.iter().map(|hand| hand.0.clone()).collect();
+    Ok(crate::calculate_result(&hands)?)
+}
+
+aoc_common::python_parts!(Day07);
+
+#[pymodule]
+pub fn day07(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyHand>()?;
+    m.add_function(wrap_pyfunction!(parse_input, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_input_with_jokers, m)?)?;
+    m.add_function(wrap_pyfunction!(calculate_result, m)?)?;
+    m.add_function(wrap_pyfunction!(part1, m)?)?;
+    m.add_function(wrap_pyfunction!(part2, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,54 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use day07::{calculate_result, calculate_result_stream, calculate_result_with_jokers_stream, parse_input, parse_input_with_jokers, with_jokers};
+use proptest::prelude::*;
+
+/// Hands with distinct cards, as the ranking of equal hands is not defined.
+fn hands_from(cards: &'static str) -> impl Strategy<Value = Vec<String>> {
+    prop::collection::btree_map(cards, 1..1000_u64, 1..30)
+        .prop_map(|hands| hands.iter().map(|(cards, bid)| format!("{} {}", cards, bid)).collect())
+}
+
+fn hands() -> impl Strategy<Value = Vec<String>> {
+    hands_from("[AKQJT98765432]{5}")
+}
+
+proptest! {
+    #[test]
+    fn parse_display_roundtrip(hands in hands()) {
+        let input = hands.join("\n");
+        for parsed in [parse_input(&input)?, parse_input_with_jokers(&input)?] {
+            let output: Vec<String> = parsed.iter().map(|hand| hand.to_string()).collect();
+            prop_assert_eq!(&output, &hands);
+        }
+    }
+
+    #[test]
+    fn result_does_not_depend_on_hand_order((hands, shuffled) in hands().prop_flat_map(|hands| (Just(hands.clone()), Just(hands).prop_shuffle()))) {
+        let (input, shuffled_input) = (hands.join("\n"), shuffled.join("\n"));
+        prop_assert_eq!(calculate_result(&parse_input(&input)?)?, calculate_result(&parse_input(&shuffled_input)?)?);
+        prop_assert_eq!(calculate_result(&parse_input_with_jokers(&input)?)?, calculate_result(&parse_input_with_jokers(&shuffled_input)?)?);
+    }
+
+    #[test]
+    fn jokers_only_matter_when_present(hands in hands_from("[AKQT98765432]{5}")) {
+        let input = hands.join("\n");
+        prop_assert_eq!(calculate_result(&parse_input(&input)?)?, calculate_result(&parse_input_with_jokers(&input)?)?);
+    }
+}
+
+proptest! {
+    #[test]
+    fn stream_matches_parsed_input(hands in prop::collection::vec((prop_oneof!["[AKQJT98765432]{5}", "[AJ2]{5}"], 1..1000_u64), 1..30)) {
+        // Equal hands are allowed here, both rank them in the order of the input.
+        let input: String = hands.iter().map(|(cards, bid)| format!("{} {}\n", cards, bid)).collect();
+        prop_assert_eq!(calculate_result_stream(input.as_bytes())?, calculate_result(&parse_input(&input)?)?);
+        prop_assert_eq!(calculate_result_with_jokers_stream(input.as_bytes())?, calculate_result(&parse_input_with_jokers(&input)?)?);
+    }
+
+    #[test]
+    fn jokers_can_be_derived_from_parsed_input(hands in hands()) {
+        let input = hands.join("\n");
+        prop_assert_eq!(with_jokers(&parse_input(&input)?), parse_input_with_jokers(&input)?);
+    }
+}
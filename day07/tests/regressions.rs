@@ -0,0 +1,32 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use std::process::Command;
+
+use aoc_common::{PuzzleError, Result};
+use day07::{calculate_result, calculate_result_stream, calculate_result_with_jokers_stream, parse_input, parse_input_with_jokers};
+
+/// Part 2 used to print every sorted hand before its result, burying the answer in thousands of lines.
+#[test]
+fn part2_prints_only_the_result() {
+    let output = Command::new(env!("CARGO_BIN_EXE_day07_part2")).arg("resources/input.txt").output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 1, "{}", stdout);
+    assert!(lines[0].starts_with("Result: 248652697,"), "{}", stdout);
+}
+
+/// Winnings of large bids used to overflow and panic, found by the fuzz target.
+#[test]
+fn overflowing_winnings_fail() -> Result<()> {
+    let input = "23456 9000000000000000000\nAAAAA 9000000000000000000\n";
+    for hands in [parse_input(input)?, parse_input_with_jokers(input)?] {
+        assert!(matches!(calculate_result(&hands), Err(PuzzleError::LogicError { .. })));
+    }
+    for result in [calculate_result_stream(input.as_bytes()), calculate_result_with_jokers_stream(input.as_bytes())] {
+        assert!(matches!(result, Err(PuzzleError::LogicError { .. })));
+    }
+    let input = "23456 9000000000000000000\n";
+    assert_eq!(calculate_result(&parse_input(input)?)?, 9000000000000000000);
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use day08::{get_moves_to_solve, get_moves_to_solve_ghost, parse_input};
+
+const INPUT: &str = include_str!("../resources/input.txt");
+
+fn bench_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day08/parse");
+    group.bench_function("parse_input", |b| b.iter(|| parse_input(black_box(INPUT))));
+    group.finish();
+}
+
+fn bench_solve(c: &mut Criterion) {
+    let map = parse_input(INPUT).expect("Bundled input is valid");
+
+    let mut group = c.benchmark_group("day08/solve");
+    group.bench_function("part1", |b| b.iter(|| get_moves_to_solve(black_box(&map))));
+    group.bench_function("part2", |b| b.iter(|| get_moves_to_solve_ghost(black_box(&map))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse, bench_solve);
+criterion_main!(benches);
@@ -1,11 +1,13 @@
 use std::time::Instant;
 use anyhow::Result;
-use day08::{get_moves_to_solve, parse_input};
+use aoc_common::input::read_input;
+use aoc_common::Solution;
+use day08::{Day08, get_moves_to_solve, parse_input};
 
 fn main() -> Result<()> {
-    let input = include_str!("../../resources/input.txt");
+    let input = read_input(Day08::DAY)?;
     let now = Instant::now();
-    let map = parse_input(input)?;
+    let map = parse_input(&input)?;
     let result = get_moves_to_solve(&map)?;
     let elapsed = now.elapsed();
     println!("Result: {}, Elapsed: {:?}", result, elapsed);
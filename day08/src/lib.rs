@@ -1,10 +1,9 @@
 use std::collections::{BTreeMap};
+use std::fmt::{Display, Formatter};
+use std::sync::LazyLock;
 use regex::Regex;
-use anyhow::Result;
-use std::fmt::Display;
-use std::error::Error;
-use std::fmt;
 use rayon::prelude::*;
+use aoc_common::{PuzzleError, Result, Solution};
 
 #[derive(Eq, PartialEq, Debug, Hash, Copy, Clone)]
 pub enum Move {
@@ -15,7 +14,7 @@ pub enum Move {
 impl TryFrom<char> for Move {
     type Error = ();
 
-    fn try_from(value: char) -> Result<Self, Self::Error> {
+    fn try_from(value: char) -> std::result::Result<Self, Self::Error> {
         match value {
             'R' => Ok(Self::R),
             'L' => Ok(Self::L),
@@ -24,6 +23,15 @@ impl TryFrom<char> for Move {
     }
 }
 
+impl Display for Move {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Move::R => 'R',
+            Move::L => 'L'
+        })
+    }
+}
+
 #[derive(Debug, Eq, PartialEq)]
 pub struct Location<'a> {
     left_id: & 'a str,
@@ -36,29 +44,40 @@ pub struct Map <'a> {
     locations: BTreeMap<& 'a str, Location<'a>>
 }
 
-#[derive(Debug, Eq, PartialEq)]
-struct ParsingError;
-
-impl Error for ParsingError {}
-
-impl Display for ParsingError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Could not parse input for puzzle!")
+impl Display for Map<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for map_move in self.move_sequence.iter() {
+            write!(f, "{}", map_move)?;
+        }
+        writeln!(f)?;
+        for (id, location) in self.locations.iter() {
+            write!(f, "\n{} = ({}, {})", id, location.left_id, location.right_id)?;
+        }
+        Ok(())
     }
 }
 
-pub fn parse_input(input: &str) -> Result<Map> {
-    let mapping_re = Regex::new(r"^(?P<id>\w+)\s*=\s*\((?P<left>\w+),\s*(?P<right>\w+)\)$")?;
-    let mut lines_iter = input.lines();
+static MAPPING_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(?P<id>\w+)\s*=\s*\((?P<left>\w+),\s*(?P<right>\w+)\)$").unwrap());
+
+pub fn parse_input(input: &str) -> Result<Map<'_>> {
+    let mut lines_iter = input.lines().enumerate();
 
-    let moves: Vec<Move>= lines_iter.next().ok_or(ParsingError {})?.chars().filter_map(|x| Move::try_from(x).ok()).collect();
-    lines_iter.next();
+    let (_, move_line) = lines_iter.next().ok_or_else(|| PuzzleError::parser(0, input, input, "Missing move sequence!"))?;
+    let moves: Vec<Move> = move_line.trim_end().char_indices().map(|(index, x)| {
+        Move::try_from(x).map_err(|_| PuzzleError::parser(0, move_line, &move_line[index..index + x.len_utf8()], "Invalid move, expected `L` or `R`!"))
+    }).collect::<Result<_>>()?;
+    if moves.is_empty() {
+        return Err(PuzzleError::parser(0, move_line, move_line, "Missing move sequence!"));
+    }
+    if let Some((line_index, line)) = lines_iter.next().filter(|(_, line)| !line.trim().is_empty()) {
+        return Err(PuzzleError::parser(line_index, line, line, "Expected an empty line after the move sequence!"));
+    }
     let mut locations = BTreeMap::new();
-    for line in lines_iter {
-        let location_line_matches = mapping_re.captures(line).ok_or(ParsingError {})?;
-        let id = location_line_matches.name("id").ok_or(ParsingError {})?.as_str();
-        let left = location_line_matches.name("left").ok_or(ParsingError {})?.as_str();
-        let right = location_line_matches.name("right").ok_or(ParsingError {})?.as_str();
+    for (line_index, line) in lines_iter {
+        let location_line_matches = MAPPING_RE.captures(line).ok_or_else(|| PuzzleError::parser(line_index, line, line, "Expected `<id> = (<left>, <right>)`!"))?;
+        let id = location_line_matches.name("id").map_or("", |x| x.as_str());
+        let left = location_line_matches.name("left").map_or("", |x| x.as_str());
+        let right = location_line_matches.name("right").map_or("", |x| x.as_str());
 
         locations.insert(id, Location {
             left_id: left,
@@ -71,11 +90,19 @@ pub fn parse_input(input: &str) -> Result<Map> {
     })
 }
 
+const ENDLESS: PuzzleError = PuzzleError::LogicError { msg: "Map loops without ever reaching the destination!" };
+const MISSING_LOCATION: PuzzleError = PuzzleError::LogicError { msg: "Map does not contain the location to move to!" };
+
+/// Moves after which a walk has repeated a location at the same position in the move sequence, so it loops forever.
+fn max_moves(map: &Map) -> u64 {
+    (map.locations.len() * map.move_sequence.len()) as u64
+}
+
 pub fn get_moves_to_solve(map: &Map) -> Result<u64> {
     let mut moves_count = 0_u64;
     let mut current_element_id = "AAA";
     for map_move in map.move_sequence.iter().cycle() {
-        let current_location = map.locations.get(current_element_id).ok_or(ParsingError {})?;
+        let current_location = map.locations.get(current_element_id).ok_or(MISSING_LOCATION)?;
         current_element_id = match map_move {
             Move::R => current_location.right_id,
             Move::L => current_location.left_id
@@ -85,33 +112,69 @@ pub fn get_moves_to_solve(map: &Map) -> Result<u64> {
         if current_element_id.eq("ZZZ") {
             break;
         }
+        if moves_count > max_moves(map) {
+            return Err(ENDLESS);
+        }
     }
     Ok(moves_count)
 }
 
-fn get_moves_from_location(map: &Map, start_location: &str) -> Option<u64> {
+fn get_moves_from_location(map: &Map, start_location: &str) -> Result<u64> {
     let mut moves_count= 0_u64;
     let mut current_element_id = start_location;
     for map_move in map.move_sequence.iter().cycle() {
-        current_element_id = match map.locations.get(current_element_id)
-        {
-            Some(current_location) => match map_move {
-                Move::R => current_location.right_id,
-                Move::L => current_location.left_id
-            },
-            None => return None
+        let current_location = map.locations.get(current_element_id).ok_or(MISSING_LOCATION)?;
+        current_element_id = match map_move {
+            Move::R => current_location.right_id,
+            Move::L => current_location.left_id
         };
         moves_count += 1;
 
         if current_element_id.ends_with('Z') {
             break;
         }
+        if moves_count > max_moves(map) {
+            return Err(ENDLESS);
+        }
     }
-    Some(moves_count)
+    Ok(moves_count)
 }
 
 pub fn get_moves_to_solve_ghost(map: &Map) -> Result<u64> {
-    let moves_to_z_location: Vec<u64> = map.locations.par_iter().filter(|(id, _)| id.ends_with('A')).map(| (x, _)| *x).filter_map(|x| get_moves_from_location(&map, x)).collect();
-    let moves_count: u64 = moves_to_z_location.par_iter().cloned().reduce(|| 1_u64, num::integer::lcm);
-    Ok(moves_count)
-}
\ No newline at end of file
+    let moves_to_z_location: Vec<u64> = map.locations.par_iter().filter(|(id, _)| id.ends_with('A')).map(| (x, _)| *x).map(|x| get_moves_from_location(map, x)).collect::<Result<_>>()?;
+    if moves_to_z_location.is_empty() {
+        return Err(PuzzleError::LogicError { msg: "Map does not contain any start location!" });
+    }
+    moves_to_z_location.into_iter().try_fold(1_u64, |moves_count, moves| {
+        (moves_count / num::integer::gcd(moves_count, moves))
+            .checked_mul(moves)
+            .ok_or(PuzzleError::LogicError { msg: "Moves do not fit into 64 bits!" })
+    })
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input<'a> = Map<'a>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1> {
+        get_moves_to_solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2> {
+        get_moves_to_solve_ghost(input)
+    }
+}
+
+#[cfg(feature = "python")]
+pub mod python;
+
+#[cfg(feature = "wasm")]
+aoc_common::wasm_exports!(Day08);
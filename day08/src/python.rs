@@ -0,0 +1,48 @@
+use pyo3::prelude::*;
+
+use crate::Day08;
+
+/// Map of the network. The parsed map borrows from the input, so the checked input is kept and parsed again by
+/// every solving function.
+#[pyclass(name = "Map", frozen)]
+pub struct PyMap {
+    input: String,
+}
+
+#[pymethods]
+impl PyMap {
+    fn __str__(&self) -> PyResult<String> {
+        Ok(crate::parse_input(&self.input)?.to_string())
+    }
+}
+
+#[pyfunction]
+fn parse_input(input: &str) -> PyResult<PyMap> {
+    crate::parse_input(input)?;
+    Ok(PyMap { input: input.to_string() })
+}
+
+/// Moves from `AAA` to `ZZZ`.
+#[pyfunction]
+fn get_moves_to_solve(map: PyRef<'_, PyMap>) -> PyResult<u64> {
+    Ok(crate::get_moves_to_solve(&crate::parse_input(&map.input)?)?)
+}
+
+/// Moves until all ghosts starting at the nodes ending in `A` are on nodes ending in `Z` at once.
+#[pyfunction]
+fn get_moves_to_solve_ghost(map: PyRef<'_, PyMap>) -> PyResult<u64> {
+    Ok(crate::get_moves_to_solve_ghost(&crate::parse_input(&map.input)?)?)
+}
+
+aoc_common::python_parts!(Day08);
+
+#[pymodule]
+pub fn day08(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyMap>()?;
+    m.add_function(wrap_pyfunction!(parse_input, m)?)?;
+    m.add_function(wrap_pyfunction!(get_moves_to_solve, m)?)?;
+    m.add_function(wrap_pyfunction!(get_moves_to_solve_ghost, m)?)?;
+    m.add_function(wrap_pyfunction!(part1, m)?)?;
+    m.add_function(wrap_pyfunction!(part2, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,9 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use aoc_common::examples::assert_examples;
+use day08::Day08;
+
+#[test]
+fn examples() {
+    assert_examples::<Day08>(concat!(env!("CARGO_MANIFEST_DIR"), "/resources"));
+}
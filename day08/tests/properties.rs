@@ -0,0 +1,58 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use day08::{get_moves_to_solve, get_moves_to_solve_ghost, parse_input};
+use proptest::prelude::*;
+
+/// Renders a network in the order `Map` displays it, the locations sorted by their id.
+fn render(moves: &str, mut locations: Vec<(String, String, String)>) -> String {
+    locations.sort();
+    let lines: Vec<String> = locations.iter().map(|(id, left, right)| format!("{} = ({}, {})", id, left, right)).collect();
+    format!("{}\n\n{}", moves, lines.join("\n"))
+}
+
+/// A chain `AAA -> ... -> ZZZ` of the given length, both directions of every location lead to the next one.
+fn chain(length: usize) -> Vec<(String, String, String)> {
+    let ids: Vec<String> = std::iter::once("AAA".to_string())
+        .chain((1..length).map(|index| format!("N{:02}", index)))
+        .chain(std::iter::once("ZZZ".to_string()))
+        .collect();
+    ids.windows(2).map(|pair| (pair[0].clone(), pair[1].clone(), pair[1].clone())).collect()
+}
+
+/// Ghost `index` walks `length` steps from its start `S<index>A` to its end `E<index>Z` and loops from there.
+fn ghost_cycle(index: usize, length: usize) -> Vec<(String, String, String)> {
+    let ids: Vec<String> = std::iter::once(format!("S{}A", index))
+        .chain((1..length).map(|step| format!("M{}N{}", index, step)))
+        .chain(std::iter::once(format!("E{}Z", index)))
+        .collect();
+    let mut locations: Vec<(String, String, String)> = ids.windows(2).map(|pair| (pair[0].clone(), pair[1].clone(), pair[1].clone())).collect();
+    let first_step = ids[1].clone();
+    locations.push((ids[ids.len() - 1].clone(), first_step.clone(), first_step));
+    locations
+}
+
+fn moves() -> impl Strategy<Value = String> {
+    "[LR]{1,10}"
+}
+
+proptest! {
+    #[test]
+    fn parse_display_roundtrip(moves in moves(), length in 1..20_usize) {
+        let input = render(&moves, chain(length));
+        prop_assert_eq!(parse_input(&input)?.to_string(), input);
+    }
+
+    #[test]
+    fn chain_takes_one_move_per_location(moves in moves(), length in 1..20_usize) {
+        let input = render(&moves, chain(length));
+        prop_assert_eq!(get_moves_to_solve(&parse_input(&input)?)?, length as u64);
+    }
+
+    #[test]
+    fn ghosts_meet_at_the_lcm_of_their_cycles(moves in moves(), lengths in prop::collection::vec(1..12_usize, 1..5)) {
+        let locations: Vec<(String, String, String)> = lengths.iter().enumerate().flat_map(|(index, length)| ghost_cycle(index, *length)).collect();
+        let input = render(&moves, locations);
+        let expected = lengths.iter().fold(1_u64, |result, length| num::integer::lcm(result, *length as u64));
+        prop_assert_eq!(get_moves_to_solve_ghost(&parse_input(&input)?)?, expected);
+    }
+}
@@ -0,0 +1,47 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use aoc_common::{PuzzleError, Result};
+use day08::{get_moves_to_solve, get_moves_to_solve_ghost, parse_input};
+
+/// Maps looping without reaching their destination used to be walked forever, found by the fuzz target.
+#[test]
+fn endless_loops_fail() -> Result<()> {
+    let map = parse_input("L\n\nAAA = (AAA, AAA)\n")?;
+    assert!(matches!(get_moves_to_solve(&map), Err(PuzzleError::LogicError { .. })));
+    assert!(matches!(get_moves_to_solve_ghost(&map), Err(PuzzleError::LogicError { .. })));
+    let map = parse_input("LR\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n")?;
+    assert!(matches!(get_moves_to_solve(&map), Err(PuzzleError::LogicError { .. })));
+    let map = parse_input("LR\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n")?;
+    assert_eq!(get_moves_to_solve(&map)?, 2);
+    Ok(())
+}
+
+/// Without any moves every ghost arrived after zero moves, which divided by zero, found by the fuzz target.
+#[test]
+fn empty_move_sequence_fails() {
+    let error = parse_input("\n\nAAA = (AAA, ZZZ)\n").unwrap_err();
+    assert!(matches!(error, PuzzleError::ParserError { line: 1, .. }));
+}
+
+/// Ghosts starting next to a missing location used to be left out of the answer.
+#[test]
+fn missing_ghost_location_fails() -> Result<()> {
+    let map = parse_input("L\n\nAAA = (ZZZ, ZZZ)\nBBA = (CCC, CCC)\nZZZ = (ZZZ, ZZZ)\n")?;
+    assert!(matches!(get_moves_to_solve_ghost(&map), Err(PuzzleError::LogicError { .. })));
+    Ok(())
+}
+
+/// Without any start location the ghosts used to arrive after a single move.
+#[test]
+fn missing_start_locations_fail() -> Result<()> {
+    let map = parse_input("L\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n")?;
+    assert!(matches!(get_moves_to_solve_ghost(&map), Err(PuzzleError::LogicError { .. })));
+    Ok(())
+}
+
+/// A mapping right below the move sequence used to be dropped without an error.
+#[test]
+fn mapping_on_second_line_fails() {
+    let error = parse_input("L\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap_err();
+    assert!(matches!(error, PuzzleError::ParserError { line: 2, .. }));
+}
@@ -0,0 +1,24 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use day09::{get_result_path1, get_result_path2, parse_input};
+
+const INPUT: &str = include_str!("../resources/input.txt");
+
+fn bench_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day09/parse");
+    group.bench_function("parse_input", |b| b.iter(|| parse_input(black_box(INPUT))));
+    group.finish();
+}
+
+fn bench_solve(c: &mut Criterion) {
+    let data = parse_input(INPUT).expect("Bundled input is valid");
+
+    let mut group = c.benchmark_group("day09/solve");
+    group.bench_function("part1", |b| b.iter(|| get_result_path1(black_box(&data))));
+    group.bench_function("part2", |b| b.iter(|| get_result_path2(black_box(&data))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse, bench_solve);
+criterion_main!(benches);
@@ -1,11 +1,13 @@
 use std::time::Instant;
 use anyhow::Result;
-use day09::{get_result_path2, parse_input};
+use aoc_common::input::read_input;
+use aoc_common::Solution;
+use day09::{Day09, get_result_path2, parse_input};
 
 fn main() -> Result<()> {
-    let input = include_str!("../../resources/input.txt");
+    let input = read_input(Day09::DAY)?;
     let now = Instant::now();
-    let data = parse_input(input);
+    let data = parse_input(&input)?;
     let result = get_result_path2(&data)?;
     let elapsed = now.elapsed();
     println!("Result: {}, Elapsed: {:?}", result, elapsed);
@@ -19,7 +21,7 @@ mod tests {
     #[test]
     fn run_test_input() -> Result<()>{
         let input = include_str!("../../resources/test_input.txt");
-        let data = parse_input(input);
+        let data = parse_input(input)?;
         let result = get_result_path2(&data)?;
         assert_eq!(2, result);
         Ok(())
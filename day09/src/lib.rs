@@ -1,86 +1,119 @@
-use std::error::Error;
-use std::fmt::{Display, Formatter};
-use std::str::FromStr;
-use anyhow::Result;
-
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
-enum PuzzleError <'a> {
-    LogicError {
-        msg: & 'a str
-    }
+use std::io::BufRead;
+
+use aoc_common::error::parse_number;
+use aoc_common::stream::for_each_line;
+use aoc_common::{PuzzleError, Result, Solution, StreamingSolution};
+
+fn parse_line(line_index: usize, line: &str) -> Result<Vec<i64>> {
+    line.split_whitespace().map(|x| parse_number(line_index, line, x)).collect()
 }
 
-impl <'a> Display for PuzzleError<'a> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Failed to solve puzzle: ").ok();
-        match self {
-            PuzzleError::LogicError { msg } => {
-                write!(f, "{}", msg)
-            }
-        }
-    }
+pub fn parse_input(input: &str) -> Result<Vec<Vec<i64>>> {
+    input.lines().enumerate().map(|(line_index, line)| parse_line(line_index, line)).collect()
 }
 
-impl <'a> Error for PuzzleError <'a> {}
+const OVERFLOW: PuzzleError = PuzzleError::LogicError { msg: "Prediction does not fit into 64 bits!" };
 
-pub fn parse_input(input: &str) -> Vec<Vec<i64>> {
-    input.lines().map(|x| x.split_whitespace().filter_map(|x| i64::from_str(x).ok()).collect()).collect()
+fn checked(value: Option<i64>) -> Result<i64> {
+    value.ok_or(OVERFLOW)
 }
 
-pub fn get_result_path1(data: &Vec<Vec<i64>>) -> Result<i64> {
-    let mut results = Vec::new();
-    for start_data_sequence in data {
-        let mut data_sequences = Vec::new();
-        data_sequences.push(Box::new(start_data_sequence.clone()));
-        for i in 0..start_data_sequence.len() {
-            let current_data_sequence = data_sequences.get(i).ok_or(PuzzleError::LogicError { msg: "Could not find data sequence!"})?;
-            let next_data_sequence: Box<Vec<i64>> = Box::new(current_data_sequence.iter().zip(&current_data_sequence[1..]).map(|(x, y)| y - x).collect());
-            let result = next_data_sequence.iter().all(|x| x.eq(&0_i64));
-
-            data_sequences.push(next_data_sequence);
-            if result {
-                break;
-            }
+/// The sequence followed by the differences of the previous sequence, until the differences are all zero.
+fn difference_sequences(start_data_sequence: &[i64]) -> Result<Vec<Vec<i64>>> {
+    let mut data_sequences = vec![start_data_sequence.to_vec()];
+    for i in 0..start_data_sequence.len() {
+        let current_data_sequence = data_sequences.get(i).ok_or(PuzzleError::LogicError { msg: "Could not find data sequence!"})?;
+        let next_data_sequence: Vec<i64> = current_data_sequence.iter().zip(&current_data_sequence[1..]).map(|(x, y)| checked(y.checked_sub(*x))).collect::<Result<_>>()?;
+        let result = next_data_sequence.iter().all(|x| x.eq(&0_i64));
+
+        data_sequences.push(next_data_sequence);
+        if result {
+            break;
         }
+    }
+    Ok(data_sequences)
+}
 
-        let mut prediction= 0_i64;
+fn predict_next(start_data_sequence: &[i64]) -> Result<i64> {
+    let mut prediction= 0_i64;
+    for sequence in difference_sequences(start_data_sequence)?.iter().rev() {
+        let last = sequence.last().ok_or(PuzzleError::LogicError {msg: "Last sequence element missing!"})?;
+        prediction = checked(prediction.checked_add(*last))?;
+    }
+    Ok(prediction)
+}
 
-        for sequence in data_sequences.iter().rev() {
-            let mut sequence_iter = sequence.iter();
-            let last = sequence_iter.next_back().ok_or(PuzzleError::LogicError {msg: "Last sequence element missing!"})?;
-            prediction += last;
-        }
-        results.push(prediction);
+fn predict_previous(start_data_sequence: &[i64]) -> Result<i64> {
+    let mut prediction= 0_i64;
+    for sequence in difference_sequences(start_data_sequence)?.iter().rev() {
+        let first = sequence.first().ok_or(PuzzleError::LogicError {msg: "First sequence element missing!"})?;
+        prediction = checked(first.checked_sub(prediction))?;
     }
-    Ok(results.iter().sum::<i64>())
+    Ok(prediction)
 }
 
-pub fn get_result_path2(data: &Vec<Vec<i64>>) -> Result<i64> {
-    let mut results = Vec::new();
-    for start_data_sequence in data {
-        let mut data_sequences = Vec::new();
-        data_sequences.push(Box::new(start_data_sequence.clone()));
-        for i in 0..start_data_sequence.len() {
-            let current_data_sequence = data_sequences.get(i).ok_or(PuzzleError::LogicError { msg: "Could not find data sequence!"})?;
-            let next_data_sequence: Box<Vec<i64>> = Box::new(current_data_sequence.iter().zip(&current_data_sequence[1..]).map(|(x, y)| y - x).collect());
-            let result = next_data_sequence.iter().all(|x| x.eq(&0_i64));
-
-            data_sequences.push(next_data_sequence);
-            if result {
-                break;
-            }
-        }
+fn sum_predictions(data: &[Vec<i64>], predict: fn(&[i64]) -> Result<i64>) -> Result<i64> {
+    data.iter().try_fold(0_i64, |sum, sequence| checked(sum.checked_add(predict(sequence)?)))
+}
 
-        let mut prediction= 0_i64;
+pub fn get_result_path1(data: &[Vec<i64>]) -> Result<i64> {
+    sum_predictions(data, predict_next)
+}
 
-        for sequence in data_sequences.iter().rev() {
-            let mut sequence_iter = sequence.iter();
-            let last = sequence_iter.next().ok_or(PuzzleError::LogicError {msg: "First sequence element missing!"})?;
-            prediction = last - prediction;
-        }
-        results.push(prediction);
+pub fn get_result_path2(data: &[Vec<i64>]) -> Result<i64> {
+    sum_predictions(data, predict_previous)
+}
+
+fn sum_predictions_stream<R: BufRead>(reader: R, predict: fn(&[i64]) -> Result<i64>) -> Result<i64> {
+    let mut sum = 0_i64;
+    for_each_line(reader, |line_index, line| {
+        sum = checked(sum.checked_add(predict(&parse_line(line_index, line)?)?))?;
+        Ok(())
+    })?;
+    Ok(sum)
+}
+
+pub fn get_result_path1_stream<R: BufRead>(reader: R) -> Result<i64> {
+    sum_predictions_stream(reader, predict_next)
+}
+
+pub fn get_result_path2_stream<R: BufRead>(reader: R) -> Result<i64> {
+    sum_predictions_stream(reader, predict_previous)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Input<'a> = Vec<Vec<i64>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1> {
+        get_result_path1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2> {
+        get_result_path2(input)
+    }
+}
+
+impl StreamingSolution for Day09 {
+    fn part1_stream(reader: &mut dyn BufRead) -> Result<Self::Answer1> {
+        get_result_path1_stream(reader)
+    }
+
+    fn part2_stream(reader: &mut dyn BufRead) -> Result<Self::Answer2> {
+        get_result_path2_stream(reader)
     }
-    Ok(results.iter().sum::<i64>())
 }
 
+#[cfg(feature = "python")]
+pub mod python;
 
+#[cfg(feature = "wasm")]
+aoc_common::wasm_exports!(Day09);
@@ -0,0 +1,33 @@
+use pyo3::prelude::*;
+
+use crate::Day09;
+
+/// The sequences as lists of integers.
+#[pyfunction]
+fn parse_input(input: &str) -> PyResult<Vec<Vec<i64>>> {
+    Ok(crate::parse_input(input)?)
+}
+
+/// Sum of the next values extrapolated for every sequence.
+#[pyfunction]
+fn get_result_path1(data: Vec<Vec<i64>>) -> PyResult<i64> {
+    Ok(crate::get_result_path1(&data)?)
+}
+
+/// Sum of the previous values extrapolated for every sequence.
+#[pyfunction]
+fn get_result_path2(data: Vec<Vec<i64>>) -> PyResult<i64> {
+    Ok(crate::get_result_path2(&data)?)
+}
+
+aoc_common::python_parts!(Day09);
+
+#[pymodule]
+pub fn day09(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(parse_input, m)?)?;
+    m.add_function(wrap_pyfunction!(get_result_path1, m)?)?;
+    m.add_function(wrap_pyfunction!(get_result_path2, m)?)?;
+    m.add_function(wrap_pyfunction!(part1, m)?)?;
+    m.add_function(wrap_pyfunction!(part2, m)?)?;
+    Ok(())
+}
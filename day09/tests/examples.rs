@@ -0,0 +1,14 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use aoc_common::examples::{assert_examples, assert_stream_examples};
+use day09::Day09;
+
+#[test]
+fn examples() {
+    assert_examples::<Day09>(concat!(env!("CARGO_MANIFEST_DIR"), "/resources"));
+}
+
+#[test]
+fn stream_examples() {
+    assert_stream_examples::<Day09>(concat!(env!("CARGO_MANIFEST_DIR"), "/resources"));
+}
@@ -0,0 +1,40 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use day09::{get_result_path1, get_result_path1_stream, get_result_path2, get_result_path2_stream, parse_input};
+use proptest::prelude::*;
+
+fn evaluate(coefficients: &[i64], x: i64) -> i64 {
+    coefficients.iter().rev().fold(0, |result, coefficient| result * x + coefficient)
+}
+
+/// Polynomials given by their coefficients and the number of values to sample, enough to determine them.
+fn polynomials() -> impl Strategy<Value = Vec<(Vec<i64>, i64)>> {
+    let polynomial = prop::collection::vec(-10..10_i64, 1..5)
+        .prop_flat_map(|coefficients| {
+            let min_len = coefficients.len() as i64 + 1;
+            (Just(coefficients), min_len..min_len + 8)
+        });
+    prop::collection::vec(polynomial, 1..10)
+}
+
+fn render(polynomials: &[(Vec<i64>, i64)]) -> String {
+    polynomials
+        .iter()
+        .map(|(coefficients, len)| (0..*len).map(|x| evaluate(coefficients, x).to_string()).collect::<Vec<String>>().join(" "))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+proptest! {
+    #[test]
+    fn extrapolation_matches_polynomial(polynomials in polynomials()) {
+        let input = render(&polynomials);
+        let data = parse_input(&input)?;
+        let next: i64 = polynomials.iter().map(|(coefficients, len)| evaluate(coefficients, *len)).sum();
+        let previous: i64 = polynomials.iter().map(|(coefficients, _)| evaluate(coefficients, -1)).sum();
+        prop_assert_eq!(get_result_path1(&data)?, next);
+        prop_assert_eq!(get_result_path2(&data)?, previous);
+        prop_assert_eq!(get_result_path1_stream(input.as_bytes())?, next);
+        prop_assert_eq!(get_result_path2_stream(input.as_bytes())?, previous);
+    }
+}
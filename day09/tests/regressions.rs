@@ -0,0 +1,44 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use aoc_common::{PuzzleError, Result};
+use day09::{get_result_path1, get_result_path1_stream, get_result_path2, get_result_path2_stream, parse_input};
+
+fn is_overflow(result: Result<i64>) -> bool {
+    matches!(result, Err(PuzzleError::LogicError { .. }))
+}
+
+fn part1_overflows(input: &str) -> Result<bool> {
+    let overflows = is_overflow(get_result_path1(&parse_input(input)?));
+    assert_eq!(is_overflow(get_result_path1_stream(input.as_bytes())), overflows);
+    Ok(overflows)
+}
+
+fn part2_overflows(input: &str) -> Result<bool> {
+    let overflows = is_overflow(get_result_path2(&parse_input(input)?));
+    assert_eq!(is_overflow(get_result_path2_stream(input.as_bytes())), overflows);
+    Ok(overflows)
+}
+
+/// Differences of values far apart used to overflow and panic, although every value fits into an `i64`.
+#[test]
+fn overflowing_differences_fail() -> Result<()> {
+    let input = format!("{} 0 {}", i64::MIN, i64::MAX);
+    assert!(part1_overflows(&input)?);
+    assert!(part2_overflows(&input)?);
+    Ok(())
+}
+
+/// Predictions and their sum past the range of an `i64` used to overflow as well.
+#[test]
+fn overflowing_predictions_fail() -> Result<()> {
+    let rising = "0 4000000000000000000 8000000000000000000";
+    assert!(part1_overflows(rising)?);
+    assert!(!part2_overflows(rising)?);
+    let falling = "8000000000000000000 4000000000000000000 0";
+    assert!(!part1_overflows(falling)?);
+    assert!(part2_overflows(falling)?);
+    let constant = "8000000000000000000 8000000000000000000\n8000000000000000000 8000000000000000000";
+    assert!(part1_overflows(constant)?);
+    assert!(part2_overflows(constant)?);
+    Ok(())
+}
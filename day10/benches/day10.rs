@@ -0,0 +1,24 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use day10::{get_encased_cells_count, get_farthest_tile_in_loop_distance, parse_input};
+
+const INPUT: &str = include_str!("../resources/input.txt");
+
+fn bench_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day10/parse");
+    group.bench_function("parse_input", |b| b.iter(|| parse_input(black_box(INPUT))));
+    group.finish();
+}
+
+fn bench_solve(c: &mut Criterion) {
+    let tiles = parse_input(INPUT).expect("Bundled input is valid");
+
+    let mut group = c.benchmark_group("day10/solve");
+    group.bench_function("part1", |b| b.iter(|| get_farthest_tile_in_loop_distance(black_box(&tiles))));
+    group.bench_function("part2", |b| b.iter(|| get_encased_cells_count(black_box(&tiles))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse, bench_solve);
+criterion_main!(benches);
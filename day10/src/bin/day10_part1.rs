@@ -1,11 +1,13 @@
 use std::time::Instant;
 use anyhow::Result;
-use day10::{get_farthest_tile_in_loop_distance, parse_input};
+use aoc_common::input::read_input;
+use aoc_common::Solution;
+use day10::{Day10, get_farthest_tile_in_loop_distance, parse_input};
 
-fn main() -> Result<()>{
-    let input = include_str!("../../resources/input.txt");
+fn main() -> Result<()> {
+    let input = read_input(Day10::DAY)?;
     let now = Instant::now();
-    let tiles = parse_input(input)?;
+    let tiles = parse_input(&input)?;
     println!("{}", tiles);
 
     let result = get_farthest_tile_in_loop_distance(&tiles)?;
@@ -1,11 +1,13 @@
 use std::time::Instant;
 use anyhow::Result;
-use day10::{get_encased_cells_count, parse_input};
+use aoc_common::input::read_input;
+use aoc_common::Solution;
+use day10::{Day10, get_encased_cells_count, parse_input};
 
-fn main() -> Result<()>{
-    let input = include_str!("../../resources/input.txt");
+fn main() -> Result<()> {
+    let input = read_input(Day10::DAY)?;
     let now = Instant::now();
-    let tiles = parse_input(input)?;
+    let tiles = parse_input(&input)?;
     let result = get_encased_cells_count(&tiles)?;
     let elapsed = now.elapsed();
     println!("Result: {}, Elapsed: {:?}", result, elapsed);
@@ -1,35 +1,12 @@
 use std::collections::{BTreeMap, VecDeque};
-use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
 
-use anyhow::Result;
-use ndarray::{Array2, Ix2};
+use aoc_common::{PuzzleError, Result, Solution};
+use ndarray::Array2;
 
-use crate::PuzzleError::{LogicError, ParserError};
+use aoc_common::PuzzleError::LogicError;
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
-pub enum PuzzleError<'a> {
-    ParserError {
-        msg: &'a str
-    },
-    LogicError {
-        msg: &'a str
-    },
-}
-
-impl<'a> Display for PuzzleError<'a> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Failed to solve puzzle: {}",
-               match self {
-                   PuzzleError::ParserError { msg } => msg,
-                   PuzzleError::LogicError { msg } => msg
-               })
-    }
-}
-
-impl<'a> Error for PuzzleError<'a> {}
-
-#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
 pub enum TileTypes {
     Vertical,
     Horizontal,
@@ -37,20 +14,15 @@ pub enum TileTypes {
     NorthWest,
     SouthWest,
     SouthEast,
+    #[default]
     Ground,
     StartingPose,
 }
 
-impl Default for TileTypes {
-    fn default() -> Self {
-        return TileTypes::Ground;
-    }
-}
-
 impl TryFrom<char> for TileTypes {
-    type Error = PuzzleError<'static>;
+    type Error = ();
 
-    fn try_from(value: char) -> Result<Self, Self::Error> {
+    fn try_from(value: char) -> std::result::Result<Self, Self::Error> {
         match value {
             '|' => Ok(Self::Vertical),
             '-' => Ok(Self::Horizontal),
@@ -60,7 +32,7 @@ impl TryFrom<char> for TileTypes {
             'F' => Ok(Self::SouthEast),
             'S' => Ok(Self::StartingPose),
             '.' => Ok(Self::Ground),
-            _ => Err(Self::Error::ParserError { msg: "Invalid tile char!" })
+            _ => Err(())
         }
     }
 }
@@ -80,20 +52,15 @@ impl Display for TileTypes {
     }
 }
 
-#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
 pub enum EncasementTile {
     Loop,
+    #[default]
     Encased,
     Free,
     Occupied
 }
 
-impl Default for EncasementTile {
-    fn default() -> Self {
-        return EncasementTile::Encased;
-    }
-}
-
 impl Display for EncasementTile {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", match self {
@@ -107,24 +74,26 @@ impl Display for EncasementTile {
 
 pub fn parse_input(input: &str) -> Result<Array2<TileTypes>> {
     let row_count = input.lines().count();
-    let column_count = input.lines().next().ok_or(ParserError { msg: "Could not get first line!" })?.len();
+    let column_count = input.lines().next().ok_or_else(|| PuzzleError::parser(0, input, input, "Input is empty!"))?.chars().count();
     let mut output_array = Array2::<TileTypes>::default((row_count, column_count));
 
-    let mut line_index: usize = 0;
-    for line in input.lines() {
-        let mut char_index: usize = 0;
-        for char in line.chars() {
-            output_array[[line_index, char_index]] = TileTypes::try_from(char)?;
-            char_index += 1;
+    for (line_index, line) in input.lines().enumerate() {
+        if line.chars().count() != column_count {
+            return Err(PuzzleError::parser(line_index, line, line, "All lines need to be of the same length!"));
+        }
+        for (char_index, (byte_index, char)) in line.char_indices().enumerate() {
+            output_array[[line_index, char_index]] = TileTypes::try_from(char)
+                .map_err(|_| PuzzleError::parser(line_index, line, &line[byte_index..byte_index + char.len_utf8()], "Invalid tile char!"))?;
         }
-        line_index += 1;
     }
     Ok(output_array)
 }
 
 fn process_neighbor(tiles: &Array2<TileTypes>, nodes_to_process: &mut VecDeque<((usize, usize), TileTypes)>, distance_map: &mut BTreeMap<(usize, usize), u64>, neighbor_index: (usize, usize), new_distance: u64) {
+    let Some(&neighbor_node) = tiles.get(neighbor_index) else {
+        return;
+    };
     if !distance_map.contains_key(&neighbor_index) {
-        let neighbor_node = tiles[neighbor_index];
         nodes_to_process.push_back((neighbor_index, neighbor_node));
         distance_map.insert(neighbor_index, new_distance);
     } else {
@@ -155,14 +124,17 @@ fn get_western_node(y: usize, x: usize) -> Option<(usize, usize)> {
     Some((y, new_x))
 }
 
+fn get_tile(tiles: &Array2<TileTypes>, index: Option<(usize, usize)>) -> Option<((usize, usize), TileTypes)> {
+    index.and_then(|index| tiles.get(index).map(|tile| (index, *tile)))
+}
+
 fn get_distance_map(tiles: &Array2<TileTypes>) -> Result<BTreeMap<(usize, usize), u64>> {
     let ((start_index_y, start_index_x), _) = tiles.indexed_iter().find(|(_, tile)| TileTypes::StartingPose.eq(tile)).ok_or(LogicError { msg: "No starting element found!" })?;
     let mut nodes_to_process: VecDeque<((usize, usize), TileTypes)> = VecDeque::new();
     let mut distance_map: BTreeMap<(usize, usize), u64> = BTreeMap::new();
     distance_map.insert((start_index_y, start_index_x), 0);
 
-    if let Some(north_node) = get_northern_node(start_index_y, start_index_x) {
-        let north_tile = tiles[north_node];
+    if let Some((north_node, north_tile)) = get_tile(tiles, get_northern_node(start_index_y, start_index_x)) {
         match north_tile {
             TileTypes::Vertical | TileTypes::SouthEast | TileTypes::SouthWest => {
                 nodes_to_process.push_back((north_node, north_tile));
@@ -172,8 +144,7 @@ fn get_distance_map(tiles: &Array2<TileTypes>) -> Result<BTreeMap<(usize, usize)
         }
     }
 
-    if let Some(east_node) = get_eastern_node(start_index_y, start_index_x) {
-        let east_tile = tiles[east_node];
+    if let Some((east_node, east_tile)) = get_tile(tiles, get_eastern_node(start_index_y, start_index_x)) {
         match east_tile {
             TileTypes::Horizontal | TileTypes::NorthWest | TileTypes::SouthWest => {
                 nodes_to_process.push_back((east_node, east_tile));
@@ -183,8 +154,7 @@ fn get_distance_map(tiles: &Array2<TileTypes>) -> Result<BTreeMap<(usize, usize)
         }
     }
 
-    if let Some(south_node) = get_southern_node(start_index_y, start_index_x) {
-        let south_tile = tiles[south_node];
+    if let Some((south_node, south_tile)) = get_tile(tiles, get_southern_node(start_index_y, start_index_x)) {
         match south_tile {
             TileTypes::Vertical | TileTypes::NorthWest | TileTypes::NorthEast => {
                 nodes_to_process.push_back((south_node, south_tile));
@@ -194,8 +164,7 @@ fn get_distance_map(tiles: &Array2<TileTypes>) -> Result<BTreeMap<(usize, usize)
         }
     }
 
-    if let Some(west_node) = get_western_node(start_index_y, start_index_x) {
-        let west_tile = tiles[west_node];
+    if let Some((west_node, west_tile)) = get_tile(tiles, get_western_node(start_index_y, start_index_x)) {
         match west_tile {
             TileTypes::Horizontal | TileTypes::NorthEast | TileTypes::SouthEast => {
                 nodes_to_process.push_back((west_node, west_tile));
@@ -210,50 +179,50 @@ fn get_distance_map(tiles: &Array2<TileTypes>) -> Result<BTreeMap<(usize, usize)
         match tile {
             TileTypes::Vertical => {
                 if let Some(northern_neighbor) = get_northern_node(y, x) {
-                    process_neighbor(&tiles, &mut nodes_to_process, &mut distance_map, northern_neighbor, new_distance);
+                    process_neighbor(tiles, &mut nodes_to_process, &mut distance_map, northern_neighbor, new_distance);
                 }
                 if let Some(southern_neighbor) = get_southern_node(y, x) {
-                    process_neighbor(&tiles, &mut nodes_to_process, &mut distance_map, southern_neighbor, new_distance);
+                    process_neighbor(tiles, &mut nodes_to_process, &mut distance_map, southern_neighbor, new_distance);
                 }
             }
             TileTypes::Horizontal => {
                 if let Some(western_neighbor) = get_western_node(y, x) {
-                    process_neighbor(&tiles, &mut nodes_to_process, &mut distance_map, western_neighbor, new_distance);
+                    process_neighbor(tiles, &mut nodes_to_process, &mut distance_map, western_neighbor, new_distance);
                 }
                 if let Some(eastern_neighbor) = get_eastern_node(y, x) {
-                    process_neighbor(&tiles, &mut nodes_to_process, &mut distance_map, eastern_neighbor, new_distance);
+                    process_neighbor(tiles, &mut nodes_to_process, &mut distance_map, eastern_neighbor, new_distance);
                 }
             }
             TileTypes::NorthEast => {
                 if let Some(northern_neighbor) = get_northern_node(y, x) {
-                    process_neighbor(&tiles, &mut nodes_to_process, &mut distance_map, northern_neighbor, new_distance);
+                    process_neighbor(tiles, &mut nodes_to_process, &mut distance_map, northern_neighbor, new_distance);
                 }
                 if let Some(eastern_neighbor) = get_eastern_node(y, x) {
-                    process_neighbor(&tiles, &mut nodes_to_process, &mut distance_map, eastern_neighbor, new_distance);
+                    process_neighbor(tiles, &mut nodes_to_process, &mut distance_map, eastern_neighbor, new_distance);
                 }
             }
             TileTypes::NorthWest => {
                 if let Some(northern_neighbor) = get_northern_node(y, x) {
-                    process_neighbor(&tiles, &mut nodes_to_process, &mut distance_map, northern_neighbor, new_distance);
+                    process_neighbor(tiles, &mut nodes_to_process, &mut distance_map, northern_neighbor, new_distance);
                 }
                 if let Some(western_neighbor) = get_western_node(y, x) {
-                    process_neighbor(&tiles, &mut nodes_to_process, &mut distance_map, western_neighbor, new_distance);
+                    process_neighbor(tiles, &mut nodes_to_process, &mut distance_map, western_neighbor, new_distance);
                 }
             }
             TileTypes::SouthWest => {
                 if let Some(southern_neighbor) = get_southern_node(y, x) {
-                    process_neighbor(&tiles, &mut nodes_to_process, &mut distance_map, southern_neighbor, new_distance);
+                    process_neighbor(tiles, &mut nodes_to_process, &mut distance_map, southern_neighbor, new_distance);
                 }
                 if let Some(western_neighbor) = get_western_node(y, x) {
-                    process_neighbor(&tiles, &mut nodes_to_process, &mut distance_map, western_neighbor, new_distance);
+                    process_neighbor(tiles, &mut nodes_to_process, &mut distance_map, western_neighbor, new_distance);
                 }
             }
             TileTypes::SouthEast => {
                 if let Some(southern_neighbor) = get_southern_node(y, x) {
-                    process_neighbor(&tiles, &mut nodes_to_process, &mut distance_map, southern_neighbor, new_distance);
+                    process_neighbor(tiles, &mut nodes_to_process, &mut distance_map, southern_neighbor, new_distance);
                 }
                 if let Some(eastern_neighbor) = get_eastern_node(y, x) {
-                    process_neighbor(&tiles, &mut nodes_to_process, &mut distance_map, eastern_neighbor, new_distance);
+                    process_neighbor(tiles, &mut nodes_to_process, &mut distance_map, eastern_neighbor, new_distance);
                 }
             }
             TileTypes::Ground => {
@@ -269,91 +238,85 @@ fn get_distance_map(tiles: &Array2<TileTypes>) -> Result<BTreeMap<(usize, usize)
 
 pub fn get_farthest_tile_in_loop_distance(tiles: &Array2<TileTypes>) -> Result<u64> {
     let distance_map = get_distance_map(tiles)?;
-    Ok(distance_map.iter().map(|(_, distance)| *distance).max().unwrap_or(0))
+    Ok(distance_map.values().copied().max().unwrap_or(0))
 }
 
-fn generate_encasement_map(shape: Ix2, distance_map: &BTreeMap<(usize, usize), u64>) -> Result<Array2<EncasementTile>> {
-    let mut encasement_map = Array2::default(shape);
+fn connects_north(tiles: &Array2<TileTypes>, index: (usize, usize)) -> bool {
+    match tiles[index] {
+        TileTypes::Vertical | TileTypes::NorthEast | TileTypes::NorthWest => true,
+        TileTypes::StartingPose => {
+            get_northern_node(index.0, index.1)
+                .and_then(|north_node| tiles.get(north_node))
+                .is_some_and(|north_tile| matches!(north_tile, TileTypes::Vertical | TileTypes::SouthEast | TileTypes::SouthWest))
+        }
+        TileTypes::Horizontal | TileTypes::SouthWest | TileTypes::SouthEast | TileTypes::Ground => false,
+    }
+}
 
-    for (&index, _) in distance_map {
-        encasement_map[index] = EncasementTile::Loop;
+fn generate_encasement_map(tiles: &Array2<TileTypes>, distance_map: &BTreeMap<(usize, usize), u64>) -> Result<Array2<EncasementTile>> {
+    let mut encasement_map = Array2::from_elem(tiles.raw_dim(), EncasementTile::Free);
+
+    // Scan every row from west to east, each loop tile with a northern connection flips between outside and inside.
+    for (y, row) in tiles.rows().into_iter().enumerate() {
+        let mut inside = false;
+        for x in 0..row.len() {
+            if distance_map.contains_key(&(y, x)) {
+                encasement_map[[y, x]] = EncasementTile::Loop;
+                if connects_north(tiles, (y, x)) {
+                    inside = !inside;
+                }
+            } else if inside {
+                encasement_map[[y, x]] = EncasementTile::Encased;
+            }
+        }
     }
 
-    let min_x = 0_usize;
-    let min_y = 0_usize;
-    let max_x = shape[1];
-    let max_y = shape[0];
+    Ok(encasement_map)
+}
 
+pub fn get_encased_cells_count(tiles: &Array2<TileTypes>) -> Result<u64> {
+    let distance_map = get_distance_map(tiles)?;
+    let encasement_map = generate_encasement_map(tiles, &distance_map)?;
+    Ok(encasement_map.iter().filter(|x| EncasementTile::Encased.eq(x)).count() as u64)
+}
 
+pub struct Day10;
 
-    let mut free_cells: VecDeque<(usize, usize)> = VecDeque::new();
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input<'a> = Array2<TileTypes>;
+    type Answer1 = u64;
+    type Answer2 = u64;
 
-    for x in min_x..max_x {
-        if encasement_map[[min_y, x]].eq(&EncasementTile::Encased) {
-            encasement_map[[min_y, x]] = EncasementTile::Free;
-            free_cells.push_back((min_y, x));
-        }
-        if encasement_map[[max_y - 1, x]].eq(&EncasementTile::Encased) {
-            encasement_map[[max_y - 1, x]] = EncasementTile::Free;
-            free_cells.push_back((max_y - 1, x));
-        }
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
     }
 
-    for y in min_y..max_y {
-        if encasement_map[[y, min_x]].eq(&EncasementTile::Encased) {
-            encasement_map[[y, min_x]] = EncasementTile::Free;
-            free_cells.push_back((y, min_x));
-        }
-        if encasement_map[[y, max_x - 1]].eq(&EncasementTile::Encased) {
-            encasement_map[[y, max_x - 1]] = EncasementTile::Free;
-            free_cells.push_back((y, max_x - 1));
-        }
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1> {
+        get_farthest_tile_in_loop_distance(input)
     }
 
-    while !free_cells.is_empty() {
-        let (y, x) = free_cells.pop_front().ok_or(LogicError { msg: "Could not get front of queue!" })?;
-        if let Some(north_index) = get_northern_node(y, x) {
-            if let Some(north_cell) = encasement_map.get(north_index) {
-                if EncasementTile::Encased.eq(north_cell) {
-                    encasement_map[north_index] = EncasementTile::Free;
-                    free_cells.push_back(north_index);
-                }
-            }
-        }
-        if let Some(east_index) = get_eastern_node(y, x) {
-            if let Some(east_tile) = encasement_map.get(east_index) {
-                if EncasementTile::Encased.eq(east_tile) {
-                    encasement_map[east_index] = EncasementTile::Free;
-                    free_cells.push_back(east_index);
-                }
-            }
-        }
-        if let Some(south_index) = get_southern_node(y, x) {
-            if let Some(south_tile) = encasement_map.get(south_index) {
-                if EncasementTile::Encased.eq(south_tile) {
-                    encasement_map[south_index] = EncasementTile::Free;
-                    free_cells.push_back(south_index);
-                }
-            }
-        }
-        if let Some(west_index) = get_western_node(y, x) {
-            if let Some(west_tile) = encasement_map.get(west_index) {
-                if EncasementTile::Encased.eq(west_tile) {
-                    encasement_map[west_index] = EncasementTile::Free;
-                    free_cells.push_back(west_index);
-                }
-            }
-        }
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2> {
+        get_encased_cells_count(input)
     }
+}
 
+#[cfg(feature = "python")]
+pub mod python;
 
-    println!("{}", encasement_map);
+#[cfg(feature = "wasm")]
+aoc_common::wasm_exports!(Day10);
 
-    Ok(encasement_map)
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-pub fn get_encased_cells_count(tiles: &Array2<TileTypes>) -> Result<u64> {
-    let distance_map = get_distance_map(tiles)?;
-    let encasement_map = generate_encasement_map(tiles.raw_dim(), &distance_map)?;
-    Ok(encasement_map.iter().filter(|x| EncasementTile::Encased.eq(x)).count() as u64)
+    #[test]
+    fn test_unequal_line_lengths() {
+        let error = parse_input("..F7.\n.FJ|\nSJ.L7").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Failed to parse input at line 2, column 1: All lines need to be of the same length! (found `.FJ|`)"
+        );
+    }
 }
@@ -0,0 +1,47 @@
+use ndarray::Array2;
+use pyo3::prelude::*;
+
+use crate::{Day10, TileTypes};
+
+/// Grid of pipe tiles.
+#[pyclass(name = "Tiles", frozen)]
+pub struct PyTiles(Array2<TileTypes>);
+
+#[pymethods]
+impl PyTiles {
+    /// Rows and columns of the grid.
+    #[getter]
+    fn shape(&self) -> (usize, usize) {
+        self.0.dim()
+    }
+}
+
+#[pyfunction]
+fn parse_input(input: &str) -> PyResult<PyTiles> {
+    Ok(PyTiles(crate::parse_input(input)?))
+}
+
+/// Steps along the loop from the start to the tile farthest away.
+#[pyfunction]
+fn get_farthest_tile_in_loop_distance(tiles: PyRef<'_, PyTiles>) -> PyResult<u64> {
+    Ok(crate::get_farthest_tile_in_loop_distance(&tiles.0)?)
+}
+
+/// Tiles enclosed by the loop.
+#[pyfunction]
+fn get_encased_cells_count(tiles: PyRef<'_, PyTiles>) -> PyResult<u64> {
+    Ok(crate::get_encased_cells_count(&tiles.0)?)
+}
+
+aoc_common::python_parts!(Day10);
+
+#[pymodule]
+pub fn day10(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyTiles>()?;
+    m.add_function(wrap_pyfunction!(parse_input, m)?)?;
+    m.add_function(wrap_pyfunction!(get_farthest_tile_in_loop_distance, m)?)?;
+    m.add_function(wrap_pyfunction!(get_encased_cells_count, m)?)?;
+    m.add_function(wrap_pyfunction!(part1, m)?)?;
+    m.add_function(wrap_pyfunction!(part2, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,96 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use day10::{get_encased_cells_count, get_farthest_tile_in_loop_distance, parse_input};
+use proptest::prelude::*;
+
+/// A rectangular loop placed in a grid with the start somewhere on the loop.
+#[derive(Debug, Clone)]
+struct PipeLoop {
+    rows: usize,
+    columns: usize,
+    top: usize,
+    left: usize,
+    height: usize,
+    width: usize,
+    start: usize,
+}
+
+impl PipeLoop {
+    fn boundary(&self) -> Vec<(usize, usize)> {
+        let (top, left, bottom, right) = (self.top, self.left, self.top + self.height - 1, self.left + self.width - 1);
+        let mut boundary: Vec<(usize, usize)> = (left..right).map(|x| (top, x)).collect();
+        boundary.extend((top..bottom).map(|y| (y, right)));
+        boundary.extend((left + 1..=right).rev().map(|x| (bottom, x)));
+        boundary.extend((top + 1..=bottom).rev().map(|y| (y, left)));
+        boundary
+    }
+
+    fn tile(&self, y: usize, x: usize) -> char {
+        let (top, left, bottom, right) = (self.top, self.left, self.top + self.height - 1, self.left + self.width - 1);
+        match (y, x) {
+            (y, x) if y == top && x == left => 'F',
+            (y, x) if y == top && x == right => '7',
+            (y, x) if y == bottom && x == left => 'L',
+            (y, x) if y == bottom && x == right => 'J',
+            (y, _) if y == top || y == bottom => '-',
+            _ => '|',
+        }
+    }
+
+    /// Renders the grid, tiles off the loop are filled with `noise` unless they are next to the start.
+    fn render(&self, noise: &[char]) -> String {
+        let boundary = self.boundary();
+        let start = boundary[self.start % boundary.len()];
+        let next_to_start = |y: usize, x: usize| y.abs_diff(start.0) + x.abs_diff(start.1) == 1;
+        (0..self.rows)
+            .map(|y| {
+                (0..self.columns)
+                    .map(|x| {
+                        if (y, x) == start {
+                            'S'
+                        } else if boundary.contains(&(y, x)) {
+                            self.tile(y, x)
+                        } else if next_to_start(y, x) {
+                            '.'
+                        } else {
+                            noise[(y * self.columns + x) % noise.len()]
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+fn pipe_loops() -> impl Strategy<Value = PipeLoop> {
+    (2..10_usize, 2..10_usize, 0..3_usize, 0..3_usize, 0..3_usize, 0..3_usize, any::<usize>()).prop_map(
+        |(height, width, top, left, bottom_margin, right_margin, start)| PipeLoop {
+            rows: top + height + bottom_margin,
+            columns: left + width + right_margin,
+            top,
+            left,
+            height,
+            width,
+            start,
+        },
+    )
+}
+
+fn noise() -> impl Strategy<Value = Vec<char>> {
+    prop::collection::vec(prop::sample::select(vec!['.', '|', '-', 'L', 'J', '7', 'F']), 1..20)
+}
+
+proptest! {
+    #[test]
+    fn farthest_tile_is_half_the_loop(pipe_loop in pipe_loops(), noise in noise()) {
+        let tiles = parse_input(&pipe_loop.render(&noise))?;
+        prop_assert_eq!(get_farthest_tile_in_loop_distance(&tiles)?, (pipe_loop.height + pipe_loop.width - 2) as u64);
+    }
+
+    #[test]
+    fn loop_encases_its_interior(pipe_loop in pipe_loops(), noise in noise()) {
+        let tiles = parse_input(&pipe_loop.render(&noise))?;
+        prop_assert_eq!(get_encased_cells_count(&tiles)?, ((pipe_loop.height - 2) * (pipe_loop.width - 2)) as u64);
+    }
+}
@@ -0,0 +1,26 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use std::error::Error;
+use std::process::Command;
+
+use day10::{get_encased_cells_count, parse_input};
+
+/// Tiles between two adjacent pipes are outside of the loop, which a flood fill over the tiles alone cannot reach.
+#[test]
+fn tiles_reachable_between_pipes_are_outside() -> Result<(), Box<dyn Error>> {
+    let input = "..........\n.S------7.\n.|F----7|.\n.||....||.\n.||....||.\n.|L-7F-J|.\n.|..||..|.\n.L--JL--J.\n..........\n";
+    let tiles = parse_input(input)?;
+    assert_eq!(get_encased_cells_count(&tiles)?, 4);
+    Ok(())
+}
+
+/// Part 2 used to print the whole encasement map before its result.
+#[test]
+fn part2_prints_only_the_result() {
+    let output = Command::new(env!("CARGO_BIN_EXE_day10_part2")).arg("resources/input.txt").output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 1, "{}", stdout);
+    assert!(lines[0].starts_with("Result: 527,"), "{}", stdout);
+}
@@ -0,0 +1,14 @@
+#![no_main]
+
+use aoc_common::{Solution, StreamingSolution};
+use day01::Day01;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(parsed) = Day01::parse(input) {
+        let _ = Day01::part1(&parsed);
+        let _ = Day01::part2(&parsed);
+    }
+    let _ = Day01::part1_stream(&mut input.as_bytes());
+    let _ = Day01::part2_stream(&mut input.as_bytes());
+});
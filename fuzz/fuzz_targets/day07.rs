@@ -0,0 +1,14 @@
+#![no_main]
+
+use aoc_common::{Solution, StreamingSolution};
+use day07::Day07;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(parsed) = Day07::parse(input) {
+        let _ = Day07::part1(&parsed);
+        let _ = Day07::part2(&parsed);
+    }
+    let _ = Day07::part1_stream(&mut input.as_bytes());
+    let _ = Day07::part2_stream(&mut input.as_bytes());
+});